@@ -0,0 +1,56 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    Usize(usize),
+}
+
+impl Answer {
+    /// The name of the answer's integer type, such as `u64`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::U32(_) => "u32",
+            Answer::U64(_) => "u64",
+            Answer::U128(_) => "u128",
+            Answer::Usize(_) => "usize",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::U32(value) => value.fmt(f),
+            Answer::U64(value) => value.fmt(f),
+            Answer::U128(value) => value.fmt(f),
+            Answer::Usize(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::U32(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::U64(value)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::U128(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Usize(value)
+    }
+}
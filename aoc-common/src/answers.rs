@@ -0,0 +1,163 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{InputSource, Solution};
+
+/// Expected answers for the real puzzle inputs, recorded in the workspace's `answers.toml`:
+///
+/// ```toml
+/// [day1]
+/// part1 = 54388
+/// part2 = "53515"
+/// ```
+///
+/// Each `[dayN]` table holds `partN` keys whose values are integers or, for answers too large for
+/// TOML's integers, strings. Answers are compared against the displayed [`Answer`](crate::Answer).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc-common lives in the workspace")
+            .join("answers.toml")
+    }
+
+    /// Loads `answers.toml`, treating a missing file as having no recorded answers.
+    pub fn load() -> Result<Self, String> {
+        let path = Answers::path();
+        let source = match fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => return Err(format!("unable to read {}: {err}", path.display())),
+        };
+        Answers::parse(&source).map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    /// Reads answers from TOML, rejecting tables other than `[dayN]` and keys other than `partN`.
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let tables: BTreeMap<String, BTreeMap<String, toml::Value>> =
+            toml::from_str(source).map_err(|err| err.to_string())?;
+
+        let mut answers = BTreeMap::new();
+        for (table, parts) in tables {
+            let day = numbered(&table, "day")
+                .ok_or_else(|| format!("unknown table `[{table}]`, expected one like `[day1]`"))?;
+            for (key, value) in parts {
+                let part = numbered(&key, "part").ok_or_else(|| {
+                    format!("unknown key `{key}` in `[{table}]`, expected `part1` or `part2`")
+                })?;
+                let answer = match value {
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    toml::Value::String(answer) => answer,
+                    _ => {
+                        return Err(format!(
+                            "the answer for day {day} part {part} must be an integer or a string"
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// Reads names like `day5`, given their `prefix`.
+fn numbered(name: &str, prefix: &str) -> Option<u8> {
+    name.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Checks one part of a day against its recorded answer; used by [`answer_tests!`](crate::answer_tests).
+///
+/// Skips instead of failing when the input file or the recorded answer is missing.
+pub fn check_recorded_answer<S: Solution>(part: u8) {
+    let input = match InputSource::Default.load::<S>() {
+        Ok(input) => input,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            eprintln!("skipping day {} part {part}: {err}", S::DAY);
+            return;
+        }
+        Err(err) => panic!("{err}"),
+    };
+    let answers = Answers::load().unwrap_or_else(|err| panic!("{err}"));
+    let Some(expected) = answers.get(S::DAY, part) else {
+        eprintln!("skipping day {} part {part}: no recorded answer", S::DAY);
+        return;
+    };
+
+    let parsed = S::parse(&input).unwrap_or_else(|err| panic!("{err}"));
+    let answer = match part {
+        1 => S::part1(&parsed),
+        2 => S::part2(&parsed),
+        _ => panic!("there is no part {part}"),
+    };
+    assert_eq!(answer.to_string(), expected, "day {} part {part}", S::DAY);
+}
+
+/// Generates a test per part that checks a day's [`Solution`] against `answers.toml`.
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod answers {
+            use super::*;
+
+            #[test]
+            fn part1() {
+                $crate::check_recorded_answer::<$solution>(1);
+            }
+
+            #[test]
+            fn part2() {
+                $crate::check_recorded_answer::<$solution>(2);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# real inputs\nday5.part1 = 331445006\n\n\
+             [ day1 ]\npart1 = 54388 # checked\npart2 = \"53515\"\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(1, 1), Some("54388"));
+        assert_eq!(answers.get(1, 2), Some("53515"));
+        assert_eq!(answers.get(5, 1), Some("331445006"));
+        assert_eq!(answers.get(5, 2), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("[day1]\npart1 = 3\npart1 = 4").is_err());
+        assert_eq!(
+            Answers::parse("[dy2]").unwrap_err(),
+            "unknown table `[dy2]`, expected one like `[day1]`"
+        );
+        assert_eq!(
+            Answers::parse("[day1]\nprt1 = 3").unwrap_err(),
+            "unknown key `prt1` in `[day1]`, expected `part1` or `part2`"
+        );
+        assert_eq!(
+            Answers::parse("[day1]\npart1 = 3.5").unwrap_err(),
+            "the answer for day 1 part 1 must be an integer or a string"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::{
+    error::Error,
+    fmt::{self, Write},
+};
+
+/// A parse failure located in the puzzle input. Lines and columns are 1-based.
+///
+/// Parsers that keep going after a failure attach the later failures as `related` errors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<u8>,
+    line: usize,
+    column: usize,
+    message: String,
+    hint: Option<String>,
+    related: Vec<ParseError>,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            line,
+            column,
+            message: message.into(),
+            hint: None,
+            related: Vec::new(),
+        }
+    }
+
+    /// Locates `fragment`, which must be a slice of `source`, and reports the error there.
+    pub fn at(source: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(source, offset_of(source, fragment));
+        ParseError::new(line, column, message)
+    }
+
+    /// Folds every error into the first one, or returns `None` when there are none.
+    pub fn from_all(errors: impl IntoIterator<Item = ParseError>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let mut first = errors.next()?;
+        first.related.extend(errors);
+        Some(first)
+    }
+
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self.related = self
+            .related
+            .into_iter()
+            .map(|related| related.with_day(day))
+            .collect();
+        self
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    pub fn related(&self) -> &[ParseError] {
+        &self.related
+    }
+
+    /// Renders the error like a compiler diagnostic: the offending line of `source`, a caret
+    /// under the column and the hint, if any. `origin` names the source, usually its path.
+    pub fn render(&self, source: &str, origin: &str) -> String {
+        let mut out = String::new();
+        self.render_into(&mut out, "error", source, origin);
+        for related in &self.related {
+            related.render_into(&mut out, "error", source, origin);
+        }
+        out
+    }
+
+    /// Renders the error as a warning, for input a lenient parser skipped over.
+    pub fn render_warning(&self, source: &str, origin: &str) -> String {
+        let mut out = String::new();
+        self.render_into(&mut out, "warning", source, origin);
+        out
+    }
+
+    fn render_into(&self, out: &mut String, label: &str, source: &str, origin: &str) {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        match self.day {
+            Some(day) => writeln!(out, "{label}[day {day}]: {}", self.message),
+            None => writeln!(out, "{label}: {}", self.message),
+        }
+        .unwrap();
+        writeln!(out, "{gutter}--> {origin}:{}:{}", self.line, self.column).unwrap();
+
+        if let Some(text) = self
+            .line
+            .checked_sub(1)
+            .and_then(|index| source.split('\n').nth(index))
+        {
+            let caret = " ".repeat(self.column.saturating_sub(1));
+            writeln!(out, "{gutter} |").unwrap();
+            writeln!(out, "{} | {}", self.line, text.trim_end_matches('\r')).unwrap();
+            writeln!(out, "{gutter} | {caret}^").unwrap();
+        }
+        if let Some(hint) = &self.hint {
+            writeln!(out, "{gutter} = hint: {hint}").unwrap();
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        match self.related.len() {
+            0 => Ok(()),
+            1 => write!(f, " (and 1 more error)"),
+            more => write!(f, " (and {more} more errors)"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn offset_of(source: &str, fragment: &str) -> usize {
+    let start = source.as_ptr() as usize;
+    let offset = (fragment.as_ptr() as usize).wrapping_sub(start);
+    offset.min(source.len())
+}
+
+fn position(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SOURCE: &str = "seeds: 1 2\n\nseed-to-soil map:\n50 98 x";
+
+    #[test]
+    fn test_at() {
+        let fragment = &SOURCE[SOURCE.find('x').unwrap()..];
+        let err = ParseError::at(SOURCE, fragment, "invalid number");
+        assert_eq!((err.line(), err.column()), (4, 7));
+
+        let err = ParseError::at(SOURCE, &SOURCE[..0], "empty");
+        assert_eq!((err.line(), err.column()), (1, 1));
+
+        let err = ParseError::at(SOURCE, &SOURCE[SOURCE.len()..], "end");
+        assert_eq!((err.line(), err.column()), (4, 8));
+    }
+
+    #[test]
+    fn test_render() {
+        let source = "Game 1: 3 blue\nGame 2: 3 purple, 4 red\n";
+        let fragment = &source[source.find("purple").unwrap()..];
+        let err = ParseError::at(source, fragment, "expected cube color")
+            .with_hint("expected one of red/green/blue")
+            .with_day(2);
+
+        assert_eq!(
+            err.render(source, "day2/input.txt"),
+            "error[day 2]: expected cube color
+ --> day2/input.txt:2:11
+  |
+2 | Game 2: 3 purple, 4 red
+  |           ^
+  = hint: expected one of red/green/blue
+"
+        );
+
+        let err = ParseError::at(source, &source[source.len()..], "missing game");
+        assert_eq!(
+            err.render(source, "<stdin>"),
+            "error: missing game
+ --> <stdin>:3:1
+  |
+3 | 
+  | ^
+"
+        );
+    }
+
+    #[test]
+    fn test_from_all() {
+        assert_eq!(ParseError::from_all(Vec::new()), None);
+
+        let err = ParseError::from_all([
+            ParseError::new(1, 9, "expected cube color"),
+            ParseError::new(4, 2, "expected cube quantity"),
+        ])
+        .unwrap()
+        .with_day(2);
+        assert_eq!(err.related().len(), 1);
+        assert_eq!(err.related()[0].day(), Some(2));
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 1, column 9: expected cube color (and 1 more error)"
+        );
+
+        let source = "Game 1: 3 purple\nGame 2: 1 red, x blue";
+        let rendered = err.render(source, "day2/input.txt");
+        assert!(rendered.starts_with("error[day 2]: expected cube color\n"));
+        assert!(rendered.contains("error[day 2]: expected cube quantity\n"));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(3, 14, "invalid number");
+        assert_eq!(err.to_string(), "line 3, column 14: invalid number");
+        assert_eq!(
+            err.with_day(5).to_string(),
+            "day 5, line 3, column 14: invalid number"
+        );
+    }
+}
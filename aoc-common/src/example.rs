@@ -0,0 +1,105 @@
+use std::process::ExitCode;
+
+use crate::{InputSource, ParseMode, Solution};
+
+/// The flags an example was run with.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExampleArgs {
+    flags: Vec<String>,
+}
+
+impl ExampleArgs {
+    pub fn has(&self, flag: &str) -> bool {
+        self.flags.iter().any(|given| given == flag)
+    }
+}
+
+/// Runs a day's example binary: reads `[FLAG...] [PATH]` from the command line, loads and parses
+/// the input, defaulting to the day's own, and prints what `report` makes of it.
+///
+/// Only the given `flags` are accepted. Bad arguments, input that can't be read and parse
+/// failures are reported on stderr, as are parse warnings.
+pub fn run_example<S: Solution>(
+    usage: &str,
+    flags: &[&str],
+    report: impl FnOnce(&ExampleArgs, &S::Parsed) -> String,
+) -> ExitCode {
+    let (args, source) = match parse_args(std::env::args().skip(1), flags) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("error: {err}\nusage: {usage}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let input = match source.load::<S>() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let origin = source.origin::<S>();
+    match S::parse_in(&input, ParseMode::Strict) {
+        Ok((parsed, warnings)) => {
+            for warning in warnings {
+                eprint!("{}", warning.render_warning(&input, &origin));
+            }
+            print!("{}", report(&args, &parsed));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprint!("{}", err.render(&input, &origin));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(
+    args: impl IntoIterator<Item = String>,
+    flags: &[&str],
+) -> Result<(ExampleArgs, InputSource), String> {
+    let mut given = ExampleArgs::default();
+    let mut path = None;
+    for arg in args {
+        if flags.contains(&arg.as_str()) {
+            given.flags.push(arg);
+        } else if arg.starts_with('-') && arg != "-" {
+            return Err(format!("unknown flag `{arg}`"));
+        } else if let Some(first) = &path {
+            return Err(format!("unexpected `{arg}` after the input path `{first}`"));
+        } else {
+            path = Some(arg);
+        }
+    }
+
+    let source = path.map_or(InputSource::Default, |path| InputSource::from_arg(&path));
+    Ok((given, source))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<(ExampleArgs, InputSource), String> {
+        parse_args(args.iter().map(|arg| arg.to_string()), &["--csv"])
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let (given, source) = args(&["--csv", "cards.txt"]).unwrap();
+        assert!(given.has("--csv"));
+        assert_eq!(source, InputSource::from_arg("cards.txt"));
+
+        let (given, source) = args(&["-"]).unwrap();
+        assert!(!given.has("--csv"));
+        assert_eq!(source, InputSource::Stdin);
+
+        assert_eq!(args(&[]).unwrap().1, InputSource::Default);
+        assert_eq!(args(&["--cvs"]).unwrap_err(), "unknown flag `--cvs`");
+        assert_eq!(
+            args(&["a.txt", "b.txt"]).unwrap_err(),
+            "unexpected `b.txt` after the input path `a.txt`"
+        );
+    }
+}
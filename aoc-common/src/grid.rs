@@ -0,0 +1,304 @@
+use std::{collections::BTreeSet, fmt, ops::Range, str::FromStr};
+
+use crate::{parse, ParseError};
+
+/// Offsets to the cells sharing an edge: up, left, right, down.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+/// Offsets to every surrounding cell, in reading order.
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row, addressed by `(x, y)` with `(0, 0)` at the top left.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order.
+    ///
+    /// # Panics
+    ///
+    /// When `cells` doesn't fill a whole number of rows of `width`.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't fill rows of width {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Every cell with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero width, which only an empty grid can have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The up to four cells sharing an edge with `(x, y)`.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    /// The up to eight cells surrounding `(x, y)`, including diagonals.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.offsets(x, y, &SURROUNDING)
+    }
+
+    /// Finds every horizontal run of consecutive cells matching `belongs`, such as the digits of
+    /// a number. Runs never continue onto the next row.
+    pub fn spans(&self, mut belongs: impl FnMut(&T) -> bool) -> Spans {
+        let mut spans = Vec::new();
+        let mut ids = Vec::with_capacity(self.cells.len());
+        for (y, row) in self.rows().enumerate() {
+            let mut current = None;
+            for (x, cell) in row.iter().enumerate() {
+                if !belongs(cell) {
+                    current = None;
+                    ids.push(None);
+                    continue;
+                }
+                let id = *current.get_or_insert_with(|| {
+                    spans.push(Span { y, x: x..x });
+                    spans.len() - 1
+                });
+                spans[id].x.end = x + 1;
+                ids.push(Some(id));
+            }
+        }
+
+        Spans {
+            spans,
+            ids: Grid::new(self.width, ids),
+        }
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            self.get(x, y).map(|cell| ((x, y), cell))
+        })
+    }
+}
+
+/// Identifies a [`Span`] within its [`Spans`]. Ids count up from zero in reading order.
+pub type SpanId = usize;
+
+/// A horizontal run of cells on row `y`, covering the columns in `x`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub y: usize,
+    pub x: Range<usize>,
+}
+
+impl Span {
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.x.clone().map(|x| (x, self.y))
+    }
+}
+
+/// The spans found by [`Grid::spans`], along with which span each cell belongs to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spans {
+    spans: Vec<Span>,
+    ids: Grid<Option<SpanId>>,
+}
+
+impl Spans {
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    pub fn get(&self, id: SpanId) -> Option<&Span> {
+        self.spans.get(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (SpanId, &Span)> {
+        self.spans.iter().enumerate()
+    }
+
+    /// The span covering `(x, y)`, if any.
+    pub fn at(&self, x: usize, y: usize) -> Option<SpanId> {
+        self.ids.get(x, y).copied().flatten()
+    }
+
+    /// Every span with a cell among the eight surrounding `(x, y)`.
+    pub fn touching(&self, x: usize, y: usize) -> BTreeSet<SpanId> {
+        self.ids
+            .neighbors8(x, y)
+            .filter_map(|(_, &id)| id)
+            .collect()
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::char_grid(s)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const GRID: &str = "abc\ndef\nghi";
+
+    fn positions<'a, T: 'a>(
+        cells: impl Iterator<Item = ((usize, usize), &'a T)>,
+    ) -> Vec<(usize, usize)> {
+        cells.map(|(position, _)| position).collect()
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = GRID.parse::<Grid<char>>().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = GRID.parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["adg", "beh", "cfi"]
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = GRID.parse::<Grid<char>>().unwrap();
+        assert_eq!(positions(grid.neighbors4(0, 0)), vec![(1, 0), (0, 1)]);
+        assert_eq!(
+            positions(grid.neighbors8(0, 0)),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+        assert_eq!(positions(grid.neighbors4(2, 2)), vec![(2, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_spans() {
+        let grid = "467..114\n...*....\n..35..63"
+            .parse::<Grid<char>>()
+            .unwrap();
+        let spans = grid.spans(char::is_ascii_digit);
+
+        assert_eq!(spans.len(), 4);
+        assert_eq!(spans.get(0), Some(&Span { y: 0, x: 0..3 }));
+        assert_eq!(spans.get(3), Some(&Span { y: 2, x: 6..8 }));
+        assert_eq!(
+            spans.get(2).unwrap().cells().collect::<Vec<_>>(),
+            vec![(2, 2), (3, 2)]
+        );
+        assert_eq!(spans.at(1, 0), Some(0));
+        assert_eq!(spans.at(3, 0), None);
+        assert_eq!(spans.touching(3, 1), BTreeSet::from([0, 2]));
+        assert_eq!(spans.touching(0, 2), BTreeSet::new());
+    }
+
+    #[test]
+    fn test_spans_stop_at_row_ends() {
+        let grid = "..12\n34..".parse::<Grid<char>>().unwrap();
+        let spans = grid.spans(char::is_ascii_digit);
+        assert_eq!(
+            spans
+                .iter()
+                .map(|(_, span)| span.clone())
+                .collect::<Vec<_>>(),
+            vec![Span { y: 0, x: 2..4 }, Span { y: 1, x: 0..2 }]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = "".parse::<Grid<char>>().unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.neighbors8(0, 0).count(), 0);
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Solution;
+
+/// Overrides the default input location with `$AOC_INPUT_DIR/dayN/input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// An explicit file path.
+    Path(PathBuf),
+    /// Standard input, selected with `-`.
+    Stdin,
+    /// `$AOC_INPUT_DIR/dayN/input.txt` when set, otherwise `input.txt` next to the day's manifest.
+    #[default]
+    Default,
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    pub fn load<S: Solution>(&self) -> Result<String, InputError> {
+        let result = match self.path::<S>() {
+            Some(path) => fs::read_to_string(path),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+
+        result.map_err(|source| InputError {
+            location: self.origin::<S>(),
+            source,
+        })
+    }
+
+    /// Describes where the input is read from, for diagnostics.
+    pub fn origin<S: Solution>(&self) -> String {
+        match self.path::<S>() {
+            Some(path) => path.display().to_string(),
+            None => "<stdin>".to_string(),
+        }
+    }
+
+    fn path<S: Solution>(&self) -> Option<PathBuf> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Default => Some(default_path(
+                S::DAY,
+                Path::new(S::MANIFEST_DIR),
+                env::var_os(INPUT_DIR_VAR).map(PathBuf::from),
+            )),
+        }
+    }
+}
+
+fn default_path(day: u8, manifest_dir: &Path, input_dir: Option<PathBuf>) -> PathBuf {
+    match input_dir {
+        Some(dir) => dir.join(format!("day{day}")).join("input.txt"),
+        None => manifest_dir.join("input.txt"),
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    location: String,
+    source: io::Error,
+}
+
+impl InputError {
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    pub fn kind(&self) -> io::ErrorKind {
+        self.source.kind()
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unable to read {}: {}", self.location, self.source)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("day5/example.txt"),
+            InputSource::Path(PathBuf::from("day5/example.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_path() {
+        let manifest_dir = Path::new("/workspace/day5");
+        assert_eq!(
+            default_path(5, manifest_dir, None),
+            PathBuf::from("/workspace/day5/input.txt")
+        );
+        assert_eq!(
+            default_path(5, manifest_dir, Some(PathBuf::from("/inputs"))),
+            PathBuf::from("/inputs/day5/input.txt")
+        );
+    }
+}
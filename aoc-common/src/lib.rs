@@ -0,0 +1,62 @@
+mod answer;
+mod answers;
+mod error;
+mod example;
+mod grid;
+mod input;
+pub mod parse;
+
+pub use answer::Answer;
+pub use answers::{check_recorded_answer, Answers};
+pub use error::ParseError;
+pub use example::{run_example, ExampleArgs};
+pub use grid::{Grid, Span, SpanId, Spans};
+pub use input::{InputError, InputSource, INPUT_DIR_VAR};
+
+/// A single day's puzzle, solved by the `aoc` runner.
+///
+/// The input is parsed once and both parts solve against the same parsed value.
+pub trait Solution {
+    const DAY: u8;
+    /// The day crate's `CARGO_MANIFEST_DIR`, where its `input.txt` lives by default.
+    const MANIFEST_DIR: &'static str;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+
+    /// Parses while skipping malformed entries, returning what was skipped as warnings.
+    ///
+    /// Days without a lenient parser fall back to the strict one.
+    fn parse_lenient(input: &str) -> Result<(Self::Parsed, Vec<ParseError>), ParseError> {
+        Self::parse(input).map(|parsed| (parsed, Vec::new()))
+    }
+
+    /// Lines showing how part 1 reaches its answer, printed by `aoc run --trace`.
+    ///
+    /// Days without a trace return none.
+    fn trace(_parsed: &Self::Parsed) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn parse_in(
+        input: &str,
+        mode: ParseMode,
+    ) -> Result<(Self::Parsed, Vec<ParseError>), ParseError> {
+        match mode {
+            ParseMode::Strict => Self::parse(input).map(|parsed| (parsed, Vec::new())),
+            ParseMode::Lenient => Self::parse_lenient(input),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Fail on any malformed input.
+    #[default]
+    Strict,
+    /// Skip malformed input and report it as warnings.
+    Lenient,
+}
@@ -0,0 +1,122 @@
+//! Building blocks shared by the days' parsers.
+//!
+//! Each helper takes the whole `source` next to the piece of it being parsed, so that errors
+//! point at the offending line and column of the puzzle input.
+
+use std::str::FromStr;
+
+use crate::{Grid, ParseError};
+
+/// Parses a single number, reporting `word` as the culprit if it isn't one.
+pub fn number<T: FromStr>(source: &str, word: &str) -> Result<T, ParseError> {
+    word.parse()
+        .map_err(|_| ParseError::at(source, word, format!("`{word}` is not a number")))
+}
+
+/// Parses a whitespace-separated list of numbers, like `41 48 83 86`.
+pub fn numbers<T: FromStr>(source: &str, list: &str) -> Result<Vec<T>, ParseError> {
+    list.split_whitespace()
+        .map(|word| number(source, word))
+        .collect()
+}
+
+/// Splits a `Label: values` line at its first `:`, returning the trimmed label and the values.
+pub fn labeled<'a>(source: &str, line: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(':')
+        .map(|(label, values)| (label.trim(), values))
+        .ok_or_else(|| ParseError::at(source, line, "expected `:` after the label"))
+}
+
+/// Splits `source` into sections separated by one or more blank lines.
+///
+/// Each section is a slice of `source` without its trailing newline.
+pub fn sections(source: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in source.split_inclusive('\n') {
+        let text = line.trim_end();
+        if text.is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&source[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + text.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&source[start..end]);
+    }
+
+    sections
+}
+
+/// Parses a rectangular grid of characters, one row per line.
+pub fn char_grid(source: &str) -> Result<Grid<char>, ParseError> {
+    let rows = source
+        .lines()
+        .map(|line| line.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let width = rows.first().map_or(0, Vec::len);
+    if let Some((y, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+        return Err(ParseError::new(
+            y + 1,
+            row.len().min(width) + 1,
+            format!("row is {} cells wide, expected {width}", row.len()),
+        )
+        .with_hint("every row of the grid must be the same width"));
+    }
+
+    Ok(Grid::new(width, rows.into_iter().flatten().collect()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let source = "Time:      7  15   30";
+        let (label, values) = labeled(source, source).unwrap();
+        assert_eq!(label, "Time");
+        assert_eq!(numbers::<u32>(source, values).unwrap(), vec![7, 15, 30]);
+
+        let source = "41 4x | 83";
+        let err = numbers::<u32>(source, source).unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 4));
+        assert_eq!(err.message(), "`4x` is not a number");
+
+        let err = labeled("seeds 79 14", "seeds 79 14").unwrap_err();
+        assert_eq!(err.message(), "expected `:` after the label");
+    }
+
+    #[test]
+    fn test_sections() {
+        let source = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\r\n\n\n  \nsoil-to-fertilizer map:\n0 15 37\n";
+        assert_eq!(
+            sections(source),
+            vec![
+                "seeds: 79 14",
+                "seed-to-soil map:\n50 98 2",
+                "soil-to-fertilizer map:\n0 15 37",
+            ]
+        );
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid("#.\n.#\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.get(1, 1), Some(&'#'));
+
+        let err = char_grid("467..\n...").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 4));
+        assert_eq!(err.message(), "row is 3 cells wide, expected 5");
+    }
+}
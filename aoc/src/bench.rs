@@ -0,0 +1,287 @@
+use std::{
+    fs,
+    hint::black_box,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{InputSource, ParseMode, Solution};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cli::BenchArgs,
+    days::{self, DayVisitor, Part, Phase, RunError},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+struct Stats {
+    mean_ns: f64,
+    median_ns: f64,
+    stddev_ns: f64,
+}
+
+impl Stats {
+    fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        nanos.sort_by(f64::total_cmp);
+
+        let count = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let middle = nanos.len() / 2;
+        let median = if nanos.len() % 2 == 0 {
+            (nanos[middle - 1] + nanos[middle]) / 2.0
+        } else {
+            nanos[middle]
+        };
+        let variance = if nanos.len() > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (count - 1.0)
+        } else {
+            0.0
+        };
+
+        Stats {
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Measurement {
+    day: u8,
+    phase: Phase,
+    #[serde(flatten)]
+    stats: Stats,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Baseline {
+    measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    fn path(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("the aoc crate lives in the workspace")
+            .join("target/aoc-bench")
+            .join(format!("{name}.json"))
+    }
+
+    fn load(name: &str) -> Result<Self, String> {
+        let path = Baseline::path(name);
+        let json = fs::read_to_string(&path)
+            .map_err(|err| format!("unable to read baseline {}: {err}", path.display()))?;
+        serde_json::from_str(&json)
+            .map_err(|err| format!("invalid baseline {}: {err}", path.display()))
+    }
+
+    fn save(&self, name: &str) -> Result<PathBuf, String> {
+        let path = Baseline::path(name);
+        let write = || -> std::io::Result<()> {
+            fs::create_dir_all(path.parent().expect("baseline path has a parent"))?;
+            fs::write(&path, serde_json::to_string_pretty(self)?)
+        };
+        write().map_err(|err| format!("unable to write baseline {}: {err}", path.display()))?;
+        Ok(path)
+    }
+
+    fn find(&self, day: u8, phase: Phase) -> Option<&Stats> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.phase == phase)
+            .map(|m| &m.stats)
+    }
+}
+
+struct Bencher<'a> {
+    source: &'a InputSource,
+    mode: ParseMode,
+    parts: &'a [Part],
+    iterations: u32,
+}
+
+impl DayVisitor for Bencher<'_> {
+    type Output = Result<Vec<Measurement>, RunError>;
+
+    fn visit<S: Solution>(&mut self) -> Self::Output {
+        let input = days::load::<S>(self.source)?;
+
+        // Warm up, and surface parse errors before timing anything.
+        let (parsed, _) = days::parse::<S>(self.source, &input, self.mode)?;
+        for &part in self.parts {
+            black_box(part.solve::<S>(&parsed));
+        }
+
+        let mut parse_samples = Vec::new();
+        let mut part_samples = vec![Vec::new(); self.parts.len()];
+        for _ in 0..self.iterations {
+            let start = Instant::now();
+            let parsed = S::parse_in(black_box(&input), self.mode);
+            parse_samples.push(start.elapsed());
+            let (parsed, _) =
+                parsed.map_err(|err| days::parse_error::<S>(self.source, &input, &err))?;
+
+            for (&part, samples) in self.parts.iter().zip(&mut part_samples) {
+                let start = Instant::now();
+                black_box(part.solve::<S>(black_box(&parsed)));
+                samples.push(start.elapsed());
+            }
+        }
+
+        let parse = Measurement {
+            day: S::DAY,
+            phase: Phase::Parse,
+            stats: Stats::from_samples(&parse_samples),
+        };
+        let parts = self
+            .parts
+            .iter()
+            .zip(&part_samples)
+            .map(|(&part, samples)| Measurement {
+                day: S::DAY,
+                phase: part.into(),
+                stats: Stats::from_samples(samples),
+            });
+
+        Ok(std::iter::once(parse).chain(parts).collect())
+    }
+}
+
+pub fn bench(args: &BenchArgs) -> ExitCode {
+    let selection = &args.selection;
+    let source = match selection.input() {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let baseline = match args.baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = selection.parts();
+    let mut bencher = Bencher {
+        source: &source,
+        mode: selection.mode(),
+        parts: &parts,
+        iterations: args.iterations,
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut results = Baseline::default();
+    println!(
+        "{:>3}  {:<6}  {:>11}  {:>11}  {:>11}  {:>8}",
+        "day", "phase", "mean", "median", "stddev", "change"
+    );
+    for day in selection.days() {
+        match days::visit(day, &mut bencher) {
+            Some(Ok(measurements)) => {
+                for measurement in measurements {
+                    let stats = &measurement.stats;
+                    let change = baseline
+                        .as_ref()
+                        .and_then(|baseline| baseline.find(day, measurement.phase))
+                        .map_or_else(String::new, |base| format_change(base, stats));
+                    println!(
+                        "{day:>3}  {:<6}  {:>11}  {:>11}  {:>11}  {change:>8}",
+                        measurement.phase.name(),
+                        format_nanos(stats.mean_ns),
+                        format_nanos(stats.median_ns),
+                        format_nanos(stats.stddev_ns),
+                    );
+                    results.measurements.push(measurement);
+                }
+            }
+            Some(Err(err)) => {
+                eprintln!("{err}");
+                status = ExitCode::FAILURE;
+            }
+            None => unreachable!("day {day} is validated by the cli"),
+        }
+    }
+
+    if let Some(name) = &args.save_baseline {
+        match results.save(name) {
+            Ok(path) => eprintln!("saved baseline `{name}` to {}", path.display()),
+            Err(err) => {
+                eprintln!("error: {err}");
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    status
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+fn format_change(base: &Stats, current: &Stats) -> String {
+    if base.mean_ns == 0.0 {
+        return String::new();
+    }
+    let change = (current.mean_ns - base.mean_ns) / base.mean_ns * 100.0;
+    format!("{change:+.1}%")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stats(samples: &[u64]) -> Stats {
+        let samples = samples
+            .iter()
+            .map(|&nanos| Duration::from_nanos(nanos))
+            .collect::<Vec<_>>();
+        Stats::from_samples(&samples)
+    }
+
+    #[test]
+    fn test_stats() {
+        let odd = stats(&[30, 10, 20]);
+        assert_eq!(odd.mean_ns, 20.0);
+        assert_eq!(odd.median_ns, 20.0);
+        assert_eq!(odd.stddev_ns, 10.0);
+
+        let even = stats(&[40, 10, 20, 30]);
+        assert_eq!(even.mean_ns, 25.0);
+        assert_eq!(even.median_ns, 25.0);
+
+        assert_eq!(stats(&[7]).stddev_ns, 0.0);
+    }
+
+    #[test]
+    fn test_format_change() {
+        assert_eq!(format_change(&stats(&[100]), &stats(&[110])), "+10.0%");
+        assert_eq!(format_change(&stats(&[100]), &stats(&[75])), "-25.0%");
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline {
+            measurements: vec![Measurement {
+                day: 3,
+                phase: Phase::Part2,
+                stats: stats(&[10, 20]),
+            }],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert!(json.contains(r#""phase":"part2""#));
+
+        let loaded = serde_json::from_str::<Baseline>(&json).unwrap();
+        assert_eq!(loaded.find(3, Phase::Part2), Some(&stats(&[10, 20])));
+        assert_eq!(loaded.find(3, Phase::Parse), None);
+    }
+}
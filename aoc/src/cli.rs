@@ -0,0 +1,163 @@
+use std::{ops::RangeInclusive, str::FromStr};
+
+use aoc_common::{InputSource, ParseMode};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::days::{Part, DAYS};
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run one day, a range of days, or every day
+    Run(RunArgs),
+    /// Time parsing and solving separately for each selected day and part
+    Bench(BenchArgs),
+    /// Check answers for the real inputs against `answers.toml`
+    Verify(Selection),
+}
+
+#[derive(Debug, Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+    /// Profile heap usage of each phase; requires building with `--features dhat-heap`
+    #[arg(long)]
+    pub heap: bool,
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Before part 1's answer, print how it was reached, for days that support it
+    #[arg(long)]
+    pub trace: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One `day N part P: answer` line per part
+    #[default]
+    Text,
+    /// A JSON array with one object per solved part, including timings and peak memory
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: Selection,
+    /// Timed iterations per day, after one untimed warm-up run
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub iterations: u32,
+    /// Save the results under this baseline name
+    #[arg(long, value_name = "NAME")]
+    pub save_baseline: Option<String>,
+    /// Compare the results against a previously saved baseline
+    #[arg(long, value_name = "NAME")]
+    pub baseline: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct Selection {
+    /// A single day (`5`) or an inclusive range (`2-4`); defaults to every day
+    #[arg(short, long)]
+    day: Option<DayRange>,
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file, or `-` for stdin; requires a single day
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<String>,
+    /// Skip malformed input and report it as warnings instead of failing
+    #[arg(long)]
+    lenient: bool,
+}
+
+impl Selection {
+    pub fn days(&self) -> RangeInclusive<u8> {
+        match &self.day {
+            Some(DayRange(range)) => range.clone(),
+            None => DAYS,
+        }
+    }
+
+    pub fn input(&self) -> Result<InputSource, String> {
+        match &self.input {
+            Some(arg) if self.days().count() == 1 => Ok(InputSource::from_arg(arg)),
+            Some(_) => Err("--input requires selecting a single --day".to_string()),
+            None => Ok(InputSource::Default),
+        }
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        if self.lenient {
+            ParseMode::Lenient
+        } else {
+            ParseMode::Strict
+        }
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(1) => vec![Part::One],
+            Some(2) => vec![Part::Two],
+            _ => Part::ALL.to_vec(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DayRange(RangeInclusive<u8>);
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| -> Result<u8, String> {
+            let day = day
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day `{day}`"))?;
+            if DAYS.contains(&day) {
+                Ok(day)
+            } else {
+                Err(format!(
+                    "day {day} is not solved; expected {}-{}",
+                    DAYS.start(),
+                    DAYS.end()
+                ))
+            }
+        };
+
+        let range = match s.split_once('-') {
+            Some((start, end)) => parse_day(start)?..=parse_day(end)?,
+            None => {
+                let day = parse_day(s)?;
+                day..=day
+            }
+        };
+        if range.is_empty() {
+            return Err(format!("empty day range `{s}`"));
+        }
+
+        Ok(DayRange(range))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day_range() {
+        assert_eq!(DayRange::from_str("5").unwrap().0, 5..=5);
+        assert_eq!(DayRange::from_str("2-4").unwrap().0, 2..=4);
+        assert!(DayRange::from_str("4-2").is_err());
+        assert!(DayRange::from_str("26").is_err());
+        assert!(DayRange::from_str("x").is_err());
+    }
+}
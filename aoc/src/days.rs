@@ -0,0 +1,118 @@
+use std::{fmt, ops::RangeInclusive};
+
+use serde::{Deserialize, Serialize};
+
+use aoc_common::{Answer, InputError, InputSource, ParseError, ParseMode, Solution};
+
+pub const DAYS: RangeInclusive<u8> = 1..=6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn solve<S: Solution>(self, parsed: &S::Parsed) -> Answer {
+        match self {
+            Part::One => S::part1(parsed),
+            Part::Two => S::part2(parsed),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::Part1,
+            Part::Two => Phase::Part2,
+        }
+    }
+}
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RunError {
+    Input(u8, InputError),
+    /// A rendered parse diagnostic.
+    Parse(String),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Input(day, err) => write!(f, "error[day {day}]: {err}"),
+            RunError::Parse(rendered) => f.write_str(rendered.trim_end()),
+        }
+    }
+}
+
+/// Work that is generic over a day's [`Solution`], dispatched by day number with [`visit`].
+pub trait DayVisitor {
+    type Output;
+
+    fn visit<S: Solution>(&mut self) -> Self::Output;
+}
+
+pub fn visit<V: DayVisitor>(day: u8, visitor: &mut V) -> Option<V::Output> {
+    match day {
+        1 => Some(visitor.visit::<day1::Day1>()),
+        2 => Some(visitor.visit::<day2::Day2>()),
+        3 => Some(visitor.visit::<day3::Day3>()),
+        4 => Some(visitor.visit::<day4::Day4>()),
+        5 => Some(visitor.visit::<day5::Day5>()),
+        6 => Some(visitor.visit::<day6::Day6>()),
+        _ => None,
+    }
+}
+
+pub fn load<S: Solution>(source: &InputSource) -> Result<String, RunError> {
+    source.load::<S>().map_err(|err| RunError::Input(S::DAY, err))
+}
+
+/// Parses `input`, rendering any failure against it as a diagnostic.
+pub fn parse<S: Solution>(
+    source: &InputSource,
+    input: &str,
+    mode: ParseMode,
+) -> Result<(S::Parsed, Vec<String>), RunError> {
+    let (parsed, warnings) =
+        S::parse_in(input, mode).map_err(|err| parse_error::<S>(source, input, &err))?;
+    let origin = source.origin::<S>();
+    let warnings = warnings
+        .iter()
+        .map(|warning| warning.render_warning(input, &origin))
+        .collect();
+
+    Ok((parsed, warnings))
+}
+
+pub fn parse_error<S: Solution>(source: &InputSource, input: &str, err: &ParseError) -> RunError {
+    RunError::Parse(err.render(input, &source.origin::<S>()))
+}
@@ -0,0 +1,66 @@
+//! Per-phase heap profiling with dhat, available when built with `--features dhat-heap`.
+//!
+//! Each phase runs under its own profiler, so the totals cover only that phase and dhat writes
+//! one `target/aoc-heap/day<N>-<phase>.json` file per phase for its viewer.
+
+use crate::days::Phase;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+pub const ENABLED: bool = cfg!(feature = "dhat-heap");
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Bytes allocated over the whole phase.
+    pub total_bytes: u64,
+    /// The most bytes live at any one time during the phase.
+    pub peak_bytes: usize,
+    pub allocations: u64,
+}
+
+/// Runs `f` under a fresh heap profiler and returns its result alongside the phase's heap usage.
+#[cfg(feature = "dhat-heap")]
+pub fn profile<T>(day: u8, phase: Phase, f: impl FnOnce() -> T) -> (T, HeapStats) {
+    let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives in the workspace")
+        .join("target/aoc-heap");
+    // dhat reports a failed write itself, so a missing directory only loses the json file.
+    let _ = std::fs::create_dir_all(&dir);
+    let file_name = dir.join(format!("day{day}-{}.json", phase.name().replace(' ', "")));
+
+    let profiler = dhat::Profiler::builder().file_name(file_name).build();
+    let value = f();
+    let stats = dhat::HeapStats::get();
+    drop(profiler);
+
+    let stats = HeapStats {
+        total_bytes: stats.total_bytes,
+        peak_bytes: stats.max_bytes,
+        allocations: stats.total_blocks,
+    };
+    (value, stats)
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+pub fn profile<T>(_day: u8, _phase: Phase, _f: impl FnOnce() -> T) -> (T, HeapStats) {
+    unreachable!("heap profiling is rejected by the cli without the dhat-heap feature")
+}
+
+pub fn print_table(rows: &[(u8, Phase, HeapStats)]) {
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>11}",
+        "day", "phase", "total bytes", "peak bytes", "allocations"
+    );
+    for (day, phase, stats) in rows {
+        println!(
+            "{day:>3}  {:<6}  {:>12}  {:>12}  {:>11}",
+            phase.name(),
+            stats.total_bytes,
+            stats.peak_bytes,
+            stats.allocations
+        );
+    }
+}
@@ -0,0 +1,20 @@
+mod bench;
+mod cli;
+mod days;
+mod heap;
+mod run;
+mod verify;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+use cli::{Cli, Command};
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run(args) => run::run(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Verify(selection) => verify::verify(&selection),
+    }
+}
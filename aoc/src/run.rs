@@ -0,0 +1,228 @@
+use std::{
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{Answer, InputSource, ParseMode, Solution};
+use serde::Serialize;
+
+use crate::{
+    cli::{Format, RunArgs},
+    days::{self, DayVisitor, Part, Phase, RunError},
+    heap::{self, HeapStats},
+};
+
+struct Runner<'a> {
+    source: &'a InputSource,
+    mode: ParseMode,
+    parts: &'a [Part],
+    heap: bool,
+    trace: bool,
+}
+
+/// How long a phase took and, when profiling, its heap usage.
+#[derive(Clone, Copy, Debug)]
+struct Measured {
+    duration: Duration,
+    heap: Option<HeapStats>,
+}
+
+struct Outcome {
+    parse: Measured,
+    answers: Vec<(Part, Answer, Measured)>,
+    /// Rendered diagnostics for input skipped by a lenient parse.
+    warnings: Vec<String>,
+    /// Part 1's trace, when asked for and part 1 is solved.
+    trace: Vec<String>,
+}
+
+impl Runner<'_> {
+    fn measure<T>(&self, day: u8, phase: Phase, f: impl FnOnce() -> T) -> (T, Measured) {
+        let start = Instant::now();
+        let (value, heap) = if self.heap {
+            let (value, stats) = heap::profile(day, phase, f);
+            (value, Some(stats))
+        } else {
+            (f(), None)
+        };
+        let duration = start.elapsed();
+
+        (value, Measured { duration, heap })
+    }
+}
+
+impl DayVisitor for Runner<'_> {
+    type Output = Result<Outcome, RunError>;
+
+    fn visit<S: Solution>(&mut self) -> Self::Output {
+        let input = days::load::<S>(self.source)?;
+        let (parsed, parse) = self.measure(S::DAY, Phase::Parse, || {
+            days::parse::<S>(self.source, &input, self.mode)
+        });
+        let (parsed, warnings) = parsed?;
+        let trace = if self.trace && self.parts.contains(&Part::One) {
+            S::trace(&parsed)
+        } else {
+            Vec::new()
+        };
+        let answers = self
+            .parts
+            .iter()
+            .map(|&part| {
+                let (answer, solve) =
+                    self.measure(S::DAY, part.into(), || part.solve::<S>(&parsed));
+                (part, answer, solve)
+            })
+            .collect();
+
+        Ok(Outcome {
+            parse,
+            answers,
+            warnings,
+            trace,
+        })
+    }
+}
+
+/// One solved part in `--format json` output. Dashboards diff these across runs, so the field
+/// names must not change.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    /// The answer as a string, since `u64` answers don't survive every JSON reader.
+    answer: String,
+    answer_type: &'static str,
+    parse_duration_ns: u64,
+    solve_duration_ns: u64,
+    /// The higher of the parse and solve peaks, or `null` without `--heap`.
+    peak_memory_bytes: Option<usize>,
+}
+
+impl Record {
+    fn new(day: u8, part: Part, answer: &Answer, parse: Measured, solve: Measured) -> Self {
+        let nanos = |duration: Duration| u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        let peak_memory_bytes = parse
+            .heap
+            .zip(solve.heap)
+            .map(|(parse, solve)| parse.peak_bytes.max(solve.peak_bytes));
+
+        Record {
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            answer_type: answer.type_name(),
+            parse_duration_ns: nanos(parse.duration),
+            solve_duration_ns: nanos(solve.duration),
+            peak_memory_bytes,
+        }
+    }
+}
+
+pub fn run(args: &RunArgs) -> ExitCode {
+    let selection = &args.selection;
+    let source = match selection.input() {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if args.heap && !heap::ENABLED {
+        eprintln!("error: --heap requires building with `--features dhat-heap`");
+        return ExitCode::FAILURE;
+    }
+    if args.trace && args.format == Format::Json {
+        eprintln!("error: --trace only works with text output");
+        return ExitCode::FAILURE;
+    }
+    let parts = selection.parts();
+    let mut runner = Runner {
+        source: &source,
+        mode: selection.mode(),
+        parts: &parts,
+        heap: args.heap,
+        trace: args.trace,
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut records = Vec::new();
+    let mut heap_rows = Vec::new();
+    for day in selection.days() {
+        match days::visit(day, &mut runner) {
+            Some(Ok(outcome)) => {
+                for warning in outcome.warnings {
+                    eprint!("{warning}");
+                }
+                for line in outcome.trace {
+                    println!("{line}");
+                }
+                heap_rows.extend(outcome.parse.heap.map(|stats| (day, Phase::Parse, stats)));
+                for (part, answer, solve) in outcome.answers {
+                    if args.format == Format::Text {
+                        println!("day {day} part {}: {answer}", part.number());
+                    }
+                    heap_rows.extend(solve.heap.map(|stats| (day, part.into(), stats)));
+                    records.push(Record::new(day, part, &answer, outcome.parse, solve));
+                }
+            }
+            Some(Err(err)) => {
+                eprintln!("{err}");
+                status = ExitCode::FAILURE;
+            }
+            None => unreachable!("day {day} is validated by the cli"),
+        }
+    }
+
+    match args.format {
+        Format::Text if args.heap => {
+            println!();
+            heap::print_table(&heap_rows);
+        }
+        Format::Text => {}
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&records).expect("records serialize to json")
+        ),
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_record_fields() {
+        let parse = Measured {
+            duration: Duration::from_micros(3),
+            heap: Some(HeapStats {
+                total_bytes: 4096,
+                peak_bytes: 2048,
+                allocations: 12,
+            }),
+        };
+        let solve = Measured {
+            duration: Duration::from_nanos(1500),
+            heap: Some(HeapStats {
+                total_bytes: 128,
+                peak_bytes: 64,
+                allocations: 1,
+            }),
+        };
+
+        let record = Record::new(5, Part::Two, &Answer::U64(46), parse, solve);
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":5,"part":2,"answer":"46","answer_type":"u64","parse_duration_ns":3000,"solve_duration_ns":1500,"peak_memory_bytes":2048}"#
+        );
+
+        let unprofiled = Measured {
+            heap: None,
+            ..solve
+        };
+        let record = Record::new(5, Part::Two, &Answer::U64(46), unprofiled, unprofiled);
+        assert_eq!(record.peak_memory_bytes, None);
+    }
+}
@@ -0,0 +1,146 @@
+use std::{fmt, io, process::ExitCode};
+
+use aoc_common::{Answer, Answers, InputSource, ParseMode, Solution};
+
+use crate::{
+    cli::Selection,
+    days::{self, DayVisitor, Part, RunError},
+};
+
+struct Verifier<'a> {
+    source: &'a InputSource,
+    mode: ParseMode,
+    parts: &'a [Part],
+    expected: &'a Answers,
+}
+
+enum Verdict {
+    Correct(Answer),
+    Mismatch {
+        answer: Answer,
+        expected: String,
+    },
+    /// The part isn't solved, since there is nothing to compare it with.
+    Unrecorded,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct(answer) => write!(f, "{answer} (ok)"),
+            Verdict::Mismatch { answer, expected } => {
+                write!(f, "{answer} (MISMATCH, expected {expected})")
+            }
+            Verdict::Unrecorded => write!(f, "no recorded answer"),
+        }
+    }
+}
+
+impl DayVisitor for Verifier<'_> {
+    /// `None` when the day's default input file doesn't exist, so there is nothing to verify. A
+    /// missing `--input` file is an error, as it is for `aoc run`.
+    type Output = Result<Option<Vec<(Part, Verdict)>>, RunError>;
+
+    fn visit<S: Solution>(&mut self) -> Self::Output {
+        let input = match days::load::<S>(self.source) {
+            Err(RunError::Input(_, err))
+                if *self.source == InputSource::Default
+                    && err.kind() == io::ErrorKind::NotFound =>
+            {
+                return Ok(None)
+            }
+            input => input?,
+        };
+        let (parsed, _) = days::parse::<S>(self.source, &input, self.mode)?;
+        let verdicts = self
+            .parts
+            .iter()
+            .map(|&part| {
+                let verdict = match self.expected.get(S::DAY, part.number()) {
+                    None => Verdict::Unrecorded,
+                    Some(expected) => {
+                        let answer = part.solve::<S>(&parsed);
+                        if answer.to_string() == expected {
+                            Verdict::Correct(answer)
+                        } else {
+                            Verdict::Mismatch {
+                                answer,
+                                expected: expected.to_string(),
+                            }
+                        }
+                    }
+                };
+                (part, verdict)
+            })
+            .collect();
+
+        Ok(Some(verdicts))
+    }
+}
+
+pub fn verify(selection: &Selection) -> ExitCode {
+    let source = match selection.input() {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let expected = match Answers::load() {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err.trim_end());
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = selection.parts();
+    let mut verifier = Verifier {
+        source: &source,
+        mode: selection.mode(),
+        parts: &parts,
+        expected: &expected,
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    for day in selection.days() {
+        match days::visit(day, &mut verifier) {
+            Some(Ok(Some(verdicts))) => {
+                for (part, verdict) in verdicts {
+                    if let Verdict::Mismatch { .. } = verdict {
+                        status = ExitCode::FAILURE;
+                    }
+                    println!("day {day} part {}: {verdict}", part.number());
+                }
+            }
+            Some(Ok(None)) => println!("day {day}: skipped, no input file"),
+            Some(Err(err)) => {
+                eprintln!("{err}");
+                status = ExitCode::FAILURE;
+            }
+            None => unreachable!("day {day} is validated by the cli"),
+        }
+    }
+
+    status
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_missing_input() {
+        let expected = Answers::default();
+        let missing = InputSource::from_arg("/nonexistent/input.txt");
+        let mut verifier = Verifier {
+            source: &missing,
+            mode: ParseMode::Strict,
+            parts: &Part::ALL,
+            expected: &expected,
+        };
+        assert!(matches!(
+            days::visit(3, &mut verifier),
+            Some(Err(RunError::Input(3, _)))
+        ));
+    }
+}
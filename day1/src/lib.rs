@@ -0,0 +1,26 @@
+mod solution;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        solution::parse_input(input).map_err(|err| err.with_day(Self::DAY))
+    }
+
+    fn part1(lines: &Self::Parsed) -> Answer {
+        solution::part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Parsed) -> Answer {
+        solution::part2(lines).into()
+    }
+}
+
+aoc_common::answer_tests!(Day1);
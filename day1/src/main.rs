@@ -1,6 +0,0 @@
-mod solution;
-
-fn main() {
-    println!("Part 1: {}", solution::part1());
-    println!("Part 2: {}", solution::part2());
-}
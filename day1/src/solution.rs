@@ -1,5 +1,9 @@
-pub fn part1() -> u32 {
-    let parsed_digits = parse_input("day1/input.txt")
+use aoc_common::ParseError;
+
+/// Sums the calibration values made of each line's numeric digits. Lines that only spell their
+/// digits out, which parsing accepts for part 2, have no value here and are skipped.
+pub fn part1(lines: &[String]) -> u32 {
+    let parsed_digits = lines
         .iter()
         .map(|s| {
             s.chars()
@@ -11,21 +15,21 @@ pub fn part1() -> u32 {
     sum_digits(&parsed_digits)
 }
 
-pub fn part2() -> u32 {
-    let parsed_digits = process_lines(&parse_input("day1/input.txt"));
+pub fn part2(lines: &[String]) -> u32 {
+    let parsed_digits = process_lines(lines);
     parsed_digits
         .iter()
         .map(|digits| digits.first().expect("no first") * 10 + digits.last().expect("no last"))
         .sum()
 }
 
-fn digits_to_number(digits: &[u32]) -> u32 {
-    digits.last().expect("no first") + 10 * digits.first().expect("no first")
+fn digits_to_number(digits: &[u32]) -> Option<u32> {
+    Some(digits.last()? + 10 * digits.first()?)
 }
 
 fn sum_digits(list: &[Vec<u32>]) -> u32 {
     list.iter()
-        .map(|digits| digits_to_number(digits))
+        .filter_map(|digits| digits_to_number(digits))
         .sum::<u32>()
 }
 
@@ -64,11 +68,57 @@ fn process_lines(input: &[String]) -> Vec<Vec<u32>> {
         .collect::<Vec<_>>()
 }
 
-fn parse_input(path: &str) -> Vec<String> {
-    let input = std::fs::read_to_string(path).expect("failed to read path");
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
     input
         .split('\n')
-        .filter(|l| !l.is_empty())
-        .map(ToString::to_string)
-        .collect::<Vec<String>>()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(y, line)| {
+            if let Some((x, c)) = line.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+                return Err(ParseError::new(
+                    y + 1,
+                    x + 1,
+                    format!("unexpected non-ascii character `{c}`"),
+                ));
+            }
+            let has_digit = line.chars().any(|c| c.is_ascii_digit())
+                || DIGIT_WORDS.iter().any(|word| line.contains(word));
+            if !has_digit {
+                return Err(ParseError::new(y + 1, 1, "line contains no digits")
+                    .with_hint("each line needs a digit or a spelled-out digit like `one`"));
+            }
+            Ok(line.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("1abc2\npqrstuvwx\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+
+        let err = parse_input("1abc2\na1b2\u{e9}3\n").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 5));
+
+        assert!(parse_input("two1nine\nabcone2threexyz\n").is_ok());
+    }
+
+    #[test]
+    fn test_spelled_out_example() {
+        let lines = parse_input(
+            "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n\
+             4nineeightseven2\nzoneight234\n7pqrstsixteen\n",
+        )
+        .unwrap();
+        assert_eq!(part1(&lines), 209);
+        assert_eq!(part2(&lines), 281);
+    }
 }
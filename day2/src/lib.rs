@@ -0,0 +1,39 @@
+mod parse;
+mod part1;
+mod part2;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use parse::{Cube, Game, Round};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::parse_games(input).map_err(|err| err.with_day(Self::DAY))
+    }
+
+    fn part1(games: &Self::Parsed) -> Answer {
+        part1::sum_possible_games(games).into()
+    }
+
+    fn part2(games: &Self::Parsed) -> Answer {
+        part2::calculate_power_sets(games).into()
+    }
+
+    fn parse_lenient(input: &str) -> Result<(Self::Parsed, Vec<ParseError>), ParseError> {
+        let (games, warnings) = parse::parse_games_lenient(input);
+        let warnings = warnings
+            .into_iter()
+            .map(|warning| warning.with_day(Self::DAY))
+            .collect();
+        Ok((games, warnings))
+    }
+}
+
+aoc_common::answer_tests!(Day2);
@@ -0,0 +1,259 @@
+use aoc_common::ParseError;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::digit1,
+    combinator::{all_consuming, map, map_res},
+    error::{context, VerboseError, VerboseErrorKind},
+    IResult,
+};
+
+type ParseResult<'a, T> = IResult<&'a str, T, VerboseError<&'a str>>;
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Round {
+    pub cubes: Vec<Cube>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Cube {
+    Red(u32),
+    Green(u32),
+    Blue(u32),
+}
+
+/// Parses every game, failing with all of the malformed games, rounds and cubes if there are any.
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    let (games, errors) = parse_games_lenient(input);
+    match ParseError::from_all(errors) {
+        Some(err) => Err(err),
+        None => Ok(games),
+    }
+}
+
+/// Parses every game it can, skipping malformed games and cubes and returning them as errors.
+pub fn parse_games_lenient(input: &str) -> (Vec<Game>, Vec<ParseError>) {
+    let mut errors = vec![];
+    let games = input
+        .lines()
+        .filter_map(|line| parse_game(input, line, &mut errors))
+        .collect();
+
+    (games, errors)
+}
+
+fn to_parse_error(source: &str, line: &str, err: nom::Err<VerboseError<&str>>) -> ParseError {
+    let errors = match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => err.errors,
+        nom::Err::Incomplete(_) => return ParseError::at(source, line, "incomplete game"),
+    };
+    let fragment = errors.first().map_or(line, |(fragment, _)| *fragment);
+    let context = errors.iter().find_map(|(_, kind)| match kind {
+        VerboseErrorKind::Context(context) => Some(*context),
+        _ => None,
+    });
+
+    let (message, hint) = match context {
+        Some(context @ "cube color") => (context, "expected one of red/green/blue"),
+        Some(context @ "cube quantity") => (context, "cubes are written as `<count> <color>`"),
+        Some(context @ "game id") => (context, "games start with `Game <id>: `"),
+        Some(context) => (context, "games look like `Game 1: 3 blue, 4 red; 1 red`"),
+        None => {
+            return ParseError::at(source, fragment, "unexpected characters after cube")
+                .with_hint("separate cubes with `, ` and rounds with `; `")
+        }
+    };
+
+    ParseError::at(source, fragment, format!("expected {message}")).with_hint(hint)
+}
+
+fn parse_game(source: &str, line: &str, errors: &mut Vec<ParseError>) -> Option<Game> {
+    match parse_header(line) {
+        Ok((rounds, id)) => Some(Game {
+            id,
+            rounds: rounds
+                .split("; ")
+                .map(|round| parse_round(source, round, errors))
+                .collect(),
+        }),
+        Err(err) => {
+            errors.push(to_parse_error(source, line, err));
+            None
+        }
+    }
+}
+
+fn parse_header(input: &str) -> ParseResult<'_, u32> {
+    let (input, _) = context("`Game `", tag("Game "))(input)?;
+    let (input, id) = context("game id", map_res(digit1, str::parse::<u32>))(input)?;
+    let (input, _) = context("`: `", tag(": "))(input)?;
+
+    Ok((input, id))
+}
+
+fn parse_round(source: &str, input: &str, errors: &mut Vec<ParseError>) -> Round {
+    let cubes = input
+        .split(", ")
+        .filter_map(|cube| match all_consuming(parse_cube)(cube) {
+            Ok((_, cube)) => Some(cube),
+            Err(err) => {
+                errors.push(to_parse_error(source, cube, err));
+                None
+            }
+        })
+        .collect();
+
+    Round { cubes }
+}
+
+fn parse_cube(input: &str) -> ParseResult<'_, Cube> {
+    let (input, quantity) = context("cube quantity", map_res(digit1, str::parse::<u32>))(input)?;
+    let (input, _) = context("` `", tag(" "))(input)?;
+    let (input, cube) = context(
+        "cube color",
+        alt((
+            map(tag("red"), |_| Cube::Red(quantity)),
+            map(tag("green"), |_| Cube::Green(quantity)),
+            map(tag("blue"), |_| Cube::Blue(quantity)),
+        )),
+    )(input)?;
+
+    Ok((input, cube))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cube() {
+        let input = &["3 red", "4 green", "5 blue"];
+        let expected = vec![Cube::Red(3), Cube::Green(4), Cube::Blue(5)];
+
+        input.iter().zip(expected).for_each(|(i, e)| {
+            assert_eq!(parse_cube(i).expect("error in parsing"), ("", e));
+        });
+    }
+
+    #[test]
+    fn test_parse_round() {
+        let input = &["3 blue, 4 red", "1 red, 2 green", "6 blue, 2 green"];
+        let expected = vec![
+            Round {
+                cubes: vec![Cube::Blue(3), Cube::Red(4)],
+            },
+            Round {
+                cubes: vec![Cube::Red(1), Cube::Green(2)],
+            },
+            Round {
+                cubes: vec![Cube::Blue(6), Cube::Green(2)],
+            },
+        ];
+
+        input.iter().zip(expected).for_each(|(i, e)| {
+            let mut errors = vec![];
+            assert_eq!(parse_round(i, i, &mut errors), e);
+            assert_eq!(errors, vec![]);
+        });
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let input = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        let expected = Game {
+            id: 2,
+            rounds: vec![
+                Round {
+                    cubes: vec![Cube::Blue(1), Cube::Green(2)],
+                },
+                Round {
+                    cubes: vec![Cube::Green(3), Cube::Blue(4), Cube::Red(1)],
+                },
+                Round {
+                    cubes: vec![Cube::Green(1), Cube::Blue(1)],
+                },
+            ],
+        };
+
+        assert_eq!(
+            parse_games(input).expect("error parsing game"),
+            vec![expected]
+        );
+    }
+
+    #[test]
+    fn test_parse_games_errors() {
+        let err = parse_games("Game 1: 3 blue\nGame x: 4 red").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 6));
+        assert_eq!(err.message(), "expected game id");
+
+        let err = parse_games("Game 99999999999: 3 blue").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 6));
+
+        let err = parse_games("Game 1 3 blue").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 7));
+        assert_eq!(err.message(), "expected `: `");
+    }
+
+    #[test]
+    fn test_parse_games_rejects_bad_cubes() {
+        let err = parse_games("Game 1: 3 blue, 4 red; 3 purple, 1 green").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 26));
+        assert_eq!(err.message(), "expected cube color");
+        assert_eq!(err.hint(), Some("expected one of red/green/blue"));
+
+        let err = parse_games("Game 1: 3 blue,4 red").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 15));
+
+        let err = parse_games("Game 1: 3 blue; ; 4 red").unwrap_err();
+        assert_eq!(err.message(), "expected cube quantity");
+    }
+
+    const CORRUPT_INPUT: &str = r"Game 1: 3 blue, 3 purple; 1 red
+Game x: 2 red
+Game 3: 1 green, 2 blu";
+
+    #[test]
+    fn test_strict_reports_every_error() {
+        let err = parse_games(CORRUPT_INPUT).unwrap_err();
+        let locations = std::iter::once(&err)
+            .chain(err.related())
+            .map(|err| (err.line(), err.column()))
+            .collect::<Vec<_>>();
+        assert_eq!(locations, vec![(1, 19), (2, 6), (3, 20)]);
+    }
+
+    #[test]
+    fn test_lenient_skips_and_warns() {
+        let (games, warnings) = parse_games_lenient(CORRUPT_INPUT);
+        assert_eq!(
+            games,
+            vec![
+                Game {
+                    id: 1,
+                    rounds: vec![
+                        Round {
+                            cubes: vec![Cube::Blue(3)],
+                        },
+                        Round {
+                            cubes: vec![Cube::Red(1)],
+                        },
+                    ],
+                },
+                Game {
+                    id: 3,
+                    rounds: vec![Round {
+                        cubes: vec![Cube::Green(1)],
+                    }],
+                },
+            ]
+        );
+        assert_eq!(warnings.len(), 3);
+    }
+}
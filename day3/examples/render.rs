@@ -0,0 +1,26 @@
+//! Prints the parsed schematic with parts, orphans and gears highlighted.
+//!
+//! `cargo run -p day3 --example render -- [--plain | --html] [PATH]`, reading the day's default
+//! input when no path is given.
+
+use std::process::ExitCode;
+
+use aoc_common::run_example;
+use day3::{render, Day3, Format, GEAR};
+
+fn main() -> ExitCode {
+    run_example::<Day3>(
+        "render [--plain | --html] [PATH]",
+        &["--plain", "--html"],
+        |args, schematic| {
+            let format = if args.has("--html") {
+                Format::Html
+            } else if args.has("--plain") {
+                Format::Plain
+            } else {
+                Format::Ansi
+            };
+            render(schematic, GEAR, format)
+        },
+    )
+}
@@ -0,0 +1,35 @@
+mod parse;
+mod part1;
+mod part2;
+mod query;
+mod render;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use parse::{classify, parse_with, CellType, Schematic};
+pub use part2::{calculate_gear_ratios, GEAR};
+pub use query::SymbolParts;
+pub use render::{render, Format};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::parse_input(input).map_err(|err| err.with_day(Self::DAY))
+    }
+
+    fn part1(schematic: &Self::Parsed) -> Answer {
+        part1::sum_part_numbers(schematic).into()
+    }
+
+    fn part2(schematic: &Self::Parsed) -> Answer {
+        part2::calculate_gear_ratios(schematic, GEAR).into()
+    }
+}
+
+aoc_common::answer_tests!(Day3);
@@ -1,7 +0,0 @@
-mod part1;
-mod part2;
-
-fn main() {
-    println!("part 1: {}", part1::solution());
-    println!("part 2: {}", part2::solution());
-}
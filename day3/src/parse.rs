@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+
+use aoc_common::{parse, Grid, ParseError, SpanId, Spans};
+
+#[derive(Clone, Copy, Hash, Debug, Eq, PartialEq)]
+pub enum CellType {
+    Period,
+    Symbol(char),
+    Digit(u32),
+}
+
+/// The engine schematic, with each number found as a span of digits.
+#[derive(Debug)]
+pub struct Schematic {
+    pub cells: Grid<CellType>,
+    pub numbers: Spans,
+    /// The value of each number, indexed by its span id.
+    values: Vec<u32>,
+}
+
+impl Schematic {
+    pub fn value(&self, number: SpanId) -> u32 {
+        self.values[number]
+    }
+
+    pub fn symbols(&self) -> impl Iterator<Item = ((usize, usize), char)> + '_ {
+        self.cells.iter().filter_map(|(position, cell)| match cell {
+            CellType::Symbol(symbol) => Some((position, *symbol)),
+            _ => None,
+        })
+    }
+
+    /// The numbers adjacent to `(x, y)`, diagonals included.
+    pub fn adjacent_numbers(&self, x: usize, y: usize) -> BTreeSet<SpanId> {
+        self.numbers.touching(x, y)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Schematic, ParseError> {
+    parse_with(input, classify)
+}
+
+/// Parses with a custom `classify`, which returns `None` for characters the schematic may not
+/// contain.
+pub fn parse_with(
+    input: &str,
+    classify: impl Fn(char) -> Option<CellType>,
+) -> Result<Schematic, ParseError> {
+    let grid = parse::char_grid(input)?;
+    let cells = grid
+        .iter()
+        .map(|((x, y), &c)| classify(c).ok_or((x, y, c)))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|(x, y, invalid)| {
+            ParseError::new(y + 1, x + 1, format!("unexpected character `{invalid}`"))
+        })?;
+    let cells = Grid::new(grid.width(), cells);
+
+    let numbers = cells.spans(|cell| matches!(cell, CellType::Digit(_)));
+    let values = numbers
+        .iter()
+        .map(|(_, span)| {
+            span.cells()
+                .try_fold(0u32, |value, (x, y)| match cells.get(x, y) {
+                    Some(CellType::Digit(digit)) => value.checked_mul(10)?.checked_add(*digit),
+                    _ => unreachable!("spans only cover digits"),
+                })
+                .ok_or_else(|| ParseError::new(span.y + 1, span.x.start + 1, "number is too large"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Schematic {
+        cells,
+        numbers,
+        values,
+    })
+}
+
+/// The puzzle's rule: digits and `.` are themselves, and every other character is a symbol.
+pub fn classify(c: char) -> Option<CellType> {
+    match c {
+        '.' => Some(CellType::Period),
+        c => Some(c.to_digit(10).map_or(CellType::Symbol(c), CellType::Digit)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers() {
+        let schematic = parse_input("...*..51.#\n7.........").unwrap();
+        assert_eq!(schematic.cells.get(3, 0), Some(&CellType::Symbol('*')));
+        assert_eq!(schematic.cells.get(6, 0), Some(&CellType::Digit(5)));
+        assert_eq!(schematic.numbers.len(), 2);
+        assert_eq!(schematic.numbers.at(7, 0), Some(0));
+        assert_eq!(schematic.value(0), 51);
+        assert_eq!(schematic.value(1), 7);
+        assert_eq!(
+            schematic.symbols().collect::<Vec<_>>(),
+            vec![((3, 0), '*'), ((9, 0), '#')]
+        );
+        assert_eq!(schematic.adjacent_numbers(0, 0), BTreeSet::from([1]));
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(classify('7'), Some(CellType::Digit(7)));
+        assert_eq!(classify('.'), Some(CellType::Period));
+        assert_eq!(classify('~'), Some(CellType::Symbol('~')));
+        assert_eq!(classify(':'), Some(CellType::Symbol(':')));
+
+        let only_stars = |c| match c {
+            '*' => Some(CellType::Symbol(c)),
+            '#' => Some(CellType::Period),
+            c => classify(c).filter(|cell| !matches!(cell, CellType::Symbol(_))),
+        };
+        let schematic = parse_with("12#\n..*", only_stars).unwrap();
+        assert_eq!(schematic.symbols().collect::<Vec<_>>(), vec![((2, 1), '*')]);
+
+        let err = parse_with("467..114..\n...~......", only_stars).unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 4));
+        assert_eq!(err.message(), "unexpected character `~`");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("..............\n..99999999999.").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 3));
+
+        let err = parse_input("467..\n...").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 4));
+    }
+}
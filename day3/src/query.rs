@@ -0,0 +1,95 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::SpanId;
+
+use crate::parse::Schematic;
+
+/// A symbol and the part numbers adjacent to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolParts {
+    pub position: (usize, usize),
+    pub symbol: char,
+    pub parts: BTreeSet<SpanId>,
+}
+
+impl Schematic {
+    /// Every symbol, in reading order, with the numbers around it.
+    ///
+    /// The puzzle's gears are `'*'` symbols with exactly two parts, multiplied together; other
+    /// rules are a filter and a fold over [`Schematic::values`] away.
+    pub fn symbol_parts(&self) -> impl Iterator<Item = SymbolParts> + '_ {
+        self.symbols().map(|((x, y), symbol)| SymbolParts {
+            position: (x, y),
+            symbol,
+            parts: self.adjacent_numbers(x, y),
+        })
+    }
+
+    /// Every part number with the positions of the symbols it touches.
+    pub fn part_symbols(&self) -> BTreeMap<SpanId, Vec<(usize, usize)>> {
+        let mut parts = BTreeMap::<_, Vec<_>>::new();
+        for symbol in self.symbol_parts() {
+            for part in symbol.parts {
+                parts.entry(part).or_default().push(symbol.position);
+            }
+        }
+        parts
+    }
+
+    /// The part numbers touching more than one symbol.
+    pub fn shared_parts(&self) -> BTreeSet<SpanId> {
+        self.part_symbols()
+            .into_iter()
+            .filter(|(_, symbols)| symbols.len() > 1)
+            .map(|(part, _)| part)
+            .collect()
+    }
+
+    pub fn values<'a>(
+        &'a self,
+        numbers: impl IntoIterator<Item = &'a SpanId> + 'a,
+    ) -> impl Iterator<Item = u32> + 'a {
+        numbers.into_iter().map(|&number| self.value(number))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::parse_input;
+
+    const INPUT: &str = r"12.5..
+*.*...
+3...#.
+...40.";
+
+    #[test]
+    fn test_symbol_parts() {
+        let schematic = parse_input(INPUT).unwrap();
+        let symbols = schematic
+            .symbol_parts()
+            .map(|symbol| {
+                let values = schematic.values(&symbol.parts).collect::<Vec<_>>();
+                (symbol.symbol, values)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            symbols,
+            vec![('*', vec![12, 3]), ('*', vec![12, 5]), ('#', vec![40])]
+        );
+
+        let at_least_two_summed = schematic
+            .symbol_parts()
+            .filter(|symbol| symbol.parts.len() >= 2)
+            .map(|symbol| schematic.values(&symbol.parts).sum::<u32>())
+            .collect::<Vec<_>>();
+        assert_eq!(at_least_two_summed, vec![15, 17]);
+    }
+
+    #[test]
+    fn test_shared_parts() {
+        let schematic = parse_input(INPUT).unwrap();
+        let shared = schematic.shared_parts();
+        assert_eq!(schematic.values(&shared).collect::<Vec<_>>(), vec![12]);
+        assert_eq!(schematic.part_symbols()[&0], vec![(0, 1), (2, 1)]);
+    }
+}
@@ -0,0 +1,209 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use aoc_common::Grid;
+
+use crate::parse::{CellType, Schematic};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Colored for a terminal.
+    #[default]
+    Ansi,
+    /// The bare schematic followed by lists of parts, orphans and gears.
+    Plain,
+    /// A `<pre>` block with a class and an inline color per kind of cell.
+    Html,
+}
+
+/// What a cell turned out to be once the puzzle's rules are applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Period,
+    Part,
+    /// A number that doesn't touch any symbol.
+    Orphan,
+    Gear,
+    Symbol,
+}
+
+impl Kind {
+    fn ansi(self) -> Option<&'static str> {
+        match self {
+            Kind::Period => None,
+            Kind::Part => Some("\x1b[32m"),
+            Kind::Orphan => Some("\x1b[31m"),
+            Kind::Gear => Some("\x1b[1;30;43m"),
+            Kind::Symbol => Some("\x1b[2m"),
+        }
+    }
+
+    fn html(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Kind::Period => None,
+            Kind::Part => Some(("part", "color:green")),
+            Kind::Orphan => Some(("orphan", "color:red")),
+            Kind::Gear => Some(("gear", "background:gold;font-weight:bold")),
+            Kind::Symbol => Some(("symbol", "color:gray")),
+        }
+    }
+}
+
+/// Draws the schematic with part numbers, orphaned numbers, `gear`s with exactly two parts and
+/// other symbols told apart.
+pub fn render(schematic: &Schematic, gear: char, format: Format) -> String {
+    let symbols = schematic.symbol_parts().collect::<Vec<_>>();
+    let parts = symbols
+        .iter()
+        .flat_map(|symbol| symbol.parts.iter().copied())
+        .collect::<BTreeSet<_>>();
+    let gears = symbols
+        .iter()
+        .filter(|symbol| symbol.symbol == gear && symbol.parts.len() == 2)
+        .collect::<Vec<_>>();
+    let gear_positions = gears
+        .iter()
+        .map(|gear| gear.position)
+        .collect::<BTreeSet<_>>();
+
+    let kinds = schematic
+        .cells
+        .iter()
+        .map(|((x, y), cell)| match cell {
+            CellType::Period => Kind::Period,
+            CellType::Digit(_) => match schematic.numbers.at(x, y) {
+                Some(number) if parts.contains(&number) => Kind::Part,
+                _ => Kind::Orphan,
+            },
+            CellType::Symbol(_) if gear_positions.contains(&(x, y)) => Kind::Gear,
+            CellType::Symbol(_) => Kind::Symbol,
+        })
+        .collect();
+    let kinds = Grid::new(schematic.cells.width(), kinds);
+
+    let mut out = String::new();
+    if format == Format::Html {
+        out.push_str("<pre class=\"schematic\">\n");
+    }
+    for (cells, kinds) in schematic.cells.rows().zip(kinds.rows()) {
+        render_row(&mut out, cells, kinds, format);
+        out.push('\n');
+    }
+
+    match format {
+        Format::Ansi => {}
+        Format::Html => out.push_str("</pre>\n"),
+        Format::Plain => {
+            let orphans = schematic
+                .numbers
+                .iter()
+                .filter(|(number, _)| !parts.contains(number))
+                .map(|(number, span)| {
+                    format!("{} ({},{})", schematic.value(number), span.x.start, span.y)
+                })
+                .collect::<Vec<_>>();
+            let gears = gears
+                .iter()
+                .map(|gear| {
+                    let (x, y) = gear.position;
+                    let values = schematic.values(&gear.parts).map(|v| v.to_string());
+                    format!("({x},{y}) {}", values.collect::<Vec<_>>().join("*"))
+                })
+                .collect::<Vec<_>>();
+
+            out.push('\n');
+            let parts = schematic.values(&parts).map(|v| v.to_string());
+            writeln!(out, "parts: {}", parts.collect::<Vec<_>>().join(" ")).unwrap();
+            writeln!(out, "orphans: {}", orphans.join(", ")).unwrap();
+            writeln!(out, "gears: {}", gears.join(", ")).unwrap();
+        }
+    }
+
+    out
+}
+
+/// Writes a row, styling each run of same-kind cells once rather than cell by cell.
+fn render_row(out: &mut String, cells: &[CellType], kinds: &[Kind], format: Format) {
+    let mut start = 0;
+    while start < cells.len() {
+        let kind = kinds[start];
+        let end = kinds[start..]
+            .iter()
+            .position(|&k| k != kind)
+            .map_or(cells.len(), |len| start + len);
+        let text = cells[start..end].iter().map(|&cell| match cell {
+            CellType::Period => '.',
+            CellType::Symbol(symbol) => symbol,
+            CellType::Digit(digit) => char::from_digit(digit, 10).unwrap_or('?'),
+        });
+
+        match format {
+            Format::Ansi => match kind.ansi() {
+                Some(color) => write!(out, "{color}{}\x1b[0m", text.collect::<String>()).unwrap(),
+                None => out.extend(text),
+            },
+            Format::Plain => out.extend(text),
+            Format::Html => {
+                let text = text.map(escape_html).collect::<String>();
+                match kind.html() {
+                    Some((class, style)) => write!(
+                        out,
+                        "<span class=\"{class}\" style=\"{style}\">{text}</span>"
+                    )
+                    .unwrap(),
+                    None => out.push_str(&text),
+                }
+            }
+        }
+        start = end;
+    }
+}
+
+fn escape_html(c: char) -> String {
+    match c {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        '"' => "&quot;".to_string(),
+        c => c.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::parse_input;
+
+    const INPUT: &str = "12.5..\n.*...7\n3..#..";
+
+    #[test]
+    fn test_render_ansi() {
+        let schematic = parse_input(INPUT).unwrap();
+        assert_eq!(
+            render(&schematic, '*', Format::Ansi),
+            "\x1b[32m12\x1b[0m.\x1b[31m5\x1b[0m..\n\
+             .\x1b[1;30;43m*\x1b[0m...\x1b[31m7\x1b[0m\n\
+             \x1b[32m3\x1b[0m..\x1b[2m#\x1b[0m..\n"
+        );
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let schematic = parse_input(INPUT).unwrap();
+        assert_eq!(
+            render(&schematic, '*', Format::Plain),
+            "12.5..\n.*...7\n3..#..\n\nparts: 12 3\norphans: 5 (3,0), 7 (5,1)\ngears: (1,1) 12*3\n"
+        );
+    }
+
+    #[test]
+    fn test_render_html() {
+        let schematic = parse_input("1<.").unwrap();
+        assert_eq!(
+            render(&schematic, '*', Format::Html),
+            "<pre class=\"schematic\">\n\
+             <span class=\"part\" style=\"color:green\">1</span>\
+             <span class=\"symbol\" style=\"color:gray\">&lt;</span>.\n\
+             </pre>\n"
+        );
+    }
+}
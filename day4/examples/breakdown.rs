@@ -0,0 +1,30 @@
+//! Prints each card's matching numbers, points and copies held.
+//!
+//! `cargo run -p day4 --example breakdown -- [--csv] [--count-duplicates] [PATH]`, reading the
+//! day's default input when no path is given. Cards with duplicate numbers are accepted, and only
+//! scored more than once with `--count-duplicates`.
+
+use std::process::ExitCode;
+
+use aoc_common::run_example;
+use day4::{breakdown, to_csv, to_table, Day4, Duplicates};
+
+fn main() -> ExitCode {
+    run_example::<Day4>(
+        "breakdown [--csv] [--count-duplicates] [PATH]",
+        &["--csv", "--count-duplicates"],
+        |args, cards| {
+            let duplicates = if args.has("--count-duplicates") {
+                Duplicates::CountEach
+            } else {
+                Duplicates::Collapse
+            };
+            let reports = breakdown(cards, duplicates);
+            if args.has("--csv") {
+                to_csv(&reports)
+            } else {
+                to_table(&reports)
+            }
+        },
+    )
+}
@@ -0,0 +1,167 @@
+use std::fmt::Write;
+
+use crate::{
+    parse::{Card, Duplicates},
+    part1::points,
+    part2::copies,
+};
+
+/// What a single card contributes to both parts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardReport {
+    pub id: u32,
+    pub matching: Vec<usize>,
+    /// The card's part 1 score.
+    pub points: u128,
+    /// How many copies of the card are held in part 2, original included.
+    pub copies: u128,
+}
+
+pub fn breakdown(cards: &[Card], duplicates: Duplicates) -> Vec<CardReport> {
+    cards
+        .iter()
+        .zip(copies(cards, duplicates))
+        .map(|(card, copies)| CardReport {
+            id: card.id,
+            matching: card.matching(duplicates),
+            points: points(card, duplicates),
+            copies,
+        })
+        .collect()
+}
+
+/// Formats the reports as an aligned table, with totals for points and copies.
+pub fn to_table(reports: &[CardReport]) -> String {
+    let rows = reports
+        .iter()
+        .map(|report| (report.id.to_string(), join(&report.matching), report))
+        .collect::<Vec<_>>();
+    let matching_width = rows
+        .iter()
+        .map(|(_, matching, _)| matching.len())
+        .chain(["matching".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:>5}  {:<matching_width$}  {:>6}  {:>10}",
+        "card", "matching", "points", "copies"
+    )
+    .unwrap();
+    for (id, matching, report) in &rows {
+        writeln!(
+            out,
+            "{id:>5}  {matching:<matching_width$}  {:>6}  {:>10}",
+            report.points, report.copies
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "{:>5}  {:<matching_width$}  {:>6}  {:>10}",
+        "total",
+        "",
+        reports
+            .iter()
+            .map(|report| report.points)
+            .fold(0, u128::saturating_add),
+        reports
+            .iter()
+            .map(|report| report.copies)
+            .fold(0, u128::saturating_add)
+    )
+    .unwrap();
+
+    out
+}
+
+/// Formats the reports as CSV, with the matching numbers space-separated in one column.
+pub fn to_csv(reports: &[CardReport]) -> String {
+    let mut out = String::from("card,matching,points,copies\n");
+    for report in reports {
+        writeln!(
+            out,
+            "{},{},{},{}",
+            report.id,
+            join(&report.matching),
+            report.points,
+            report.copies
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn join(numbers: &[usize]) -> String {
+    numbers
+        .iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::parse_input;
+
+    const INPUT: &str = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_breakdown() {
+        let reports = breakdown(&parse_input(INPUT).unwrap(), Duplicates::Collapse);
+        assert_eq!(
+            reports[0],
+            CardReport {
+                id: 1,
+                matching: vec![17, 48, 83, 86],
+                points: 8,
+                copies: 1,
+            }
+        );
+        assert_eq!(
+            reports
+                .iter()
+                .map(|report| report.copies)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 4, 8, 14, 1]
+        );
+    }
+
+    #[test]
+    fn test_exports() {
+        let reports = breakdown(&parse_input(INPUT).unwrap(), Duplicates::Collapse);
+        let csv = to_csv(&reports);
+        assert!(csv.starts_with("card,matching,points,copies\n1,17 48 83 86,8,1\n"));
+        assert!(csv.ends_with("6,,0,1\n"));
+
+        let table = to_table(&reports[3..4]);
+        assert_eq!(
+            table,
+            " card  matching  points      copies
+    4  84             1           8
+total                 1           8
+"
+        );
+    }
+
+    #[test]
+    fn test_table_saturates() {
+        let input = (1..=200)
+            .map(|id| format!("Card {id}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let reports = breakdown(&parse_input(&input).unwrap(), Duplicates::Collapse);
+        let table = to_table(&reports);
+        let total = table.lines().last().unwrap();
+        assert!(total.starts_with("total"));
+        assert!(total.ends_with(&u128::MAX.to_string()));
+    }
+}
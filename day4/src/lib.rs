@@ -0,0 +1,53 @@
+mod breakdown;
+mod parse;
+mod part1;
+mod part2;
+
+use aoc_common::{Answer, ParseError, ParseMode, Solution};
+
+pub use breakdown::{breakdown, to_csv, to_table, CardReport};
+pub use parse::{Card, Duplicates};
+pub use part1::calculate_points;
+pub use part2::calculate_scratch_cards;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::parse_input(input).map_err(|err| err.with_day(Self::DAY))
+    }
+
+    fn part1(cards: &Self::Parsed) -> Answer {
+        part1::calculate_points(cards, Duplicates::default()).into()
+    }
+
+    fn part2(cards: &Self::Parsed) -> Answer {
+        part2::calculate_scratch_cards(cards, Duplicates::default()).into()
+    }
+
+    fn parse_lenient(input: &str) -> Result<(Self::Parsed, Vec<ParseError>), ParseError> {
+        let (cards, warnings) =
+            parse::parse_lenient(input).map_err(|err| err.with_day(Self::DAY))?;
+        let warnings = warnings
+            .into_iter()
+            .map(|warning| warning.with_day(Self::DAY))
+            .collect();
+        Ok((cards, warnings))
+    }
+
+    /// No card is ever skipped, so both modes parse the same way and report suspicious cards as
+    /// warnings alongside the answers.
+    fn parse_in(
+        input: &str,
+        _mode: ParseMode,
+    ) -> Result<(Self::Parsed, Vec<ParseError>), ParseError> {
+        Self::parse_lenient(input)
+    }
+}
+
+aoc_common::answer_tests!(Day4);
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use aoc_common::{parse, ParseError};
+
+const CARD_HINT: &str = "cards look like `Card 1: 41 48 | 83 86`";
+
+#[derive(Clone, Debug)]
+pub struct Card {
+    pub id: u32,
+    /// The winning numbers as written, duplicates included.
+    pub winning: Vec<usize>,
+    /// My numbers as written, duplicates included.
+    pub mine: Vec<usize>,
+}
+
+/// How numbers written more than once on a card are scored.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Duplicates {
+    /// Each distinct number matches at most once, as the puzzle intends.
+    #[default]
+    Collapse,
+    /// Every one of my numbers matches once for each winning number equal to it.
+    CountEach,
+}
+
+impl Card {
+    /// My numbers that are also winning numbers, in ascending order and each listed once per
+    /// match under `duplicates`.
+    pub fn matching(&self, duplicates: Duplicates) -> Vec<usize> {
+        let mut matching = match duplicates {
+            Duplicates::Collapse => {
+                let winning = self.winning.iter().collect::<HashSet<_>>();
+                let mine = self.mine.iter().collect::<HashSet<_>>();
+                winning.intersection(&mine).map(|&&n| n).collect::<Vec<_>>()
+            }
+            Duplicates::CountEach => self
+                .mine
+                .iter()
+                .flat_map(|n| self.winning.iter().filter(move |&w| w == n))
+                .copied()
+                .collect(),
+        };
+        matching.sort_unstable();
+        matching
+    }
+
+    pub fn matches(&self, duplicates: Duplicates) -> usize {
+        self.matching(duplicates).len()
+    }
+}
+
+/// Parses every card, failing on malformed ones and ignoring what [`parse_lenient`] warns about.
+pub fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lenient(input).map(|(cards, _)| cards)
+}
+
+/// Parses every card, failing only on malformed ones. Duplicate numbers within a list, lists
+/// whose length differs from the first card's and ids that don't count up by one still score,
+/// and are returned as warnings.
+pub fn parse_lenient(input: &str) -> Result<(Vec<Card>, Vec<ParseError>), ParseError> {
+    let mut warnings = Vec::new();
+    let mut cards = Vec::new();
+    let mut first: Option<(usize, usize)> = None;
+    let mut previous_id: Option<u32> = None;
+
+    for line in input.lines() {
+        let (label, numbers) =
+            parse::labeled(input, line).map_err(|err| err.with_hint(CARD_HINT))?;
+        let id = parse_id(input, label)?;
+        let (winning_list, mine_list) = split_numbers(input, numbers)?;
+        let winning = parse_list(input, winning_list, "winning numbers", &mut warnings)?;
+        let mine = parse_list(input, mine_list, "numbers you have", &mut warnings)?;
+
+        match previous_id {
+            Some(previous) if previous.checked_add(1) != Some(id) => warnings.push(
+                ParseError::at(input, label, format!("card {id} follows card {previous}"))
+                    .with_hint("cards are numbered consecutively"),
+            ),
+            _ => {}
+        }
+        previous_id = Some(id);
+
+        let (winning_len, mine_len) = *first.get_or_insert((winning.len(), mine.len()));
+        for (list, len, expected, name) in [
+            (winning_list, winning.len(), winning_len, "winning numbers"),
+            (mine_list, mine.len(), mine_len, "numbers you have"),
+        ] {
+            if len != expected {
+                warnings.push(
+                    ParseError::at(
+                        input,
+                        list.trim_start(),
+                        format!("card has {len} {name}, but the first card has {expected}"),
+                    )
+                    .with_hint("every card should list as many numbers as the others"),
+                );
+            }
+        }
+
+        cards.push(Card { id, winning, mine });
+    }
+
+    Ok((cards, warnings))
+}
+
+fn parse_id(input: &str, label: &str) -> Result<u32, ParseError> {
+    match label.strip_prefix("Card").map(str::trim_start) {
+        Some(id) if !id.is_empty() => parse::number(input, id),
+        _ => Err(ParseError::at(input, label, "expected `Card <id>`").with_hint(CARD_HINT)),
+    }
+}
+
+fn split_numbers<'a>(input: &str, numbers: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    numbers.split_once('|').ok_or_else(|| {
+        ParseError::at(
+            input,
+            &numbers[numbers.len()..],
+            "expected `|` between the winning numbers and yours",
+        )
+        .with_hint(CARD_HINT)
+    })
+}
+
+fn parse_list(
+    input: &str,
+    list: &str,
+    name: &str,
+    warnings: &mut Vec<ParseError>,
+) -> Result<Vec<usize>, ParseError> {
+    let mut seen = HashSet::new();
+    list.split_whitespace()
+        .map(|word| {
+            let number = parse::number(input, word)?;
+            if !seen.insert(number) {
+                warnings.push(ParseError::at(
+                    input,
+                    word,
+                    format!("duplicate number `{number}` in the {name}"),
+                ));
+            }
+            Ok(number)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("Card 1: 41 48 | 83 86\nCard 2 13 32 | 61 30").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+
+        let err = parse_input("Card 1: 41 48 83 86").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 20));
+
+        let err = parse_input("Card 1: 41 4x | 83 86").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 12));
+        assert_eq!(err.message(), "`4x` is not a number");
+
+        let err = parse_input("Card: 41 48 | 83 86").unwrap_err();
+        assert_eq!(err.message(), "expected `Card <id>`");
+
+        let err = parse_input("Card  x: 41 48 | 83 86").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 7));
+    }
+
+    #[test]
+    fn test_parse_ids() {
+        let cards = parse_input("Card 11: 41 | 83\nCard  12: 7 | 7").unwrap();
+        assert_eq!(
+            cards.iter().map(|card| card.id).collect::<Vec<_>>(),
+            vec![11, 12]
+        );
+    }
+
+    const SUSPICIOUS: &str = r"Card 1: 41 48 41 | 83 86 48
+Card 3: 13 32 20 | 61 30
+Card 4: 1 21 53 | 69 82 1";
+
+    #[test]
+    fn test_validation() {
+        let (cards, warnings) = parse_lenient(SUSPICIOUS).unwrap();
+        assert_eq!(cards[0].winning, vec![41, 48, 41]);
+
+        let warnings = warnings
+            .iter()
+            .map(|warning| (warning.line(), warning.column(), warning.message()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            warnings,
+            vec![
+                (1, 15, "duplicate number `41` in the winning numbers"),
+                (2, 1, "card 3 follows card 1"),
+                (
+                    2,
+                    20,
+                    "card has 2 numbers you have, but the first card has 3"
+                ),
+            ]
+        );
+
+        assert_eq!(parse_input(SUSPICIOUS).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let (cards, _) = parse_lenient("Card 1: 41 48 41 | 41 86 41").unwrap();
+        assert_eq!(cards[0].matching(Duplicates::Collapse), vec![41]);
+        assert_eq!(
+            cards[0].matching(Duplicates::CountEach),
+            vec![41, 41, 41, 41]
+        );
+    }
+}
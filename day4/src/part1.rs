@@ -1,56 +1,29 @@
-use std::{collections::HashSet, fs::read_to_string};
-
-struct Card {
-    winning: HashSet<usize>,
-    mine: HashSet<usize>,
-}
-
-pub fn solution() -> usize {
-    let input = read_to_string("day4/input.txt").unwrap();
-    calculate_points(&parse_input(&input))
-}
-
-fn calculate_points(cards: &[Card]) -> usize {
-    cards.iter().map(|card| {
-        let intersect = card.winning.intersection(&card.mine).count();
-        if intersect < 1 {
-            return 0;
-        }
-        1 << (intersect - 1)
-    }).sum()
-}
-
-fn parse_input(input: &str) -> Vec<Card> {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(':');
-            let numbers = parts.nth(1).expect("malformed card input");
-            let (winning, mine) = parse_numbers(numbers);
-            Card { winning, mine }
-        })
-        .collect()
+use crate::parse::{Card, Duplicates};
+
+/// Sums every card's points, saturating at `u128::MAX`.
+pub fn calculate_points(cards: &[Card], duplicates: Duplicates) -> u128 {
+    cards
+        .iter()
+        .map(|card| points(card, duplicates))
+        .fold(0, u128::saturating_add)
 }
 
-fn parse_numbers(numbers: &str) -> (HashSet<usize>, HashSet<usize>) {
-    let mut parts = numbers.split('|');
-    let winning = parts.next().expect("malformed card input").trim();
-    let mine = parts.next().expect("malformed card input").trim();
-
-    (
-        winning
-            .split_whitespace()
-            .map(|n| n.parse().expect("not an ascii number"))
-            .collect::<HashSet<_>>(),
-        mine.split_whitespace()
-            .map(|n| n.parse().expect("not an ascii number"))
-            .collect::<HashSet<_>>(),
-    )
+/// One point for the first match, doubled for every match after it. Cards with more than 128
+/// matches, which only [`Duplicates::CountEach`] makes likely, saturate at `u128::MAX`.
+pub fn points(card: &Card, duplicates: Duplicates) -> u128 {
+    match card.matches(duplicates) {
+        0 => 0,
+        matches => u32::try_from(matches - 1)
+            .ok()
+            .and_then(|shift| 1u128.checked_shl(shift))
+            .unwrap_or(u128::MAX),
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::parse_input;
 
     const INPUT: &str = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -61,6 +34,26 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     #[test]
     fn example() {
-        assert_eq!(calculate_points(&parse_input(INPUT)), 13);
+        assert_eq!(
+            calculate_points(&parse_input(INPUT).unwrap(), Duplicates::Collapse),
+            13
+        );
+    }
+
+    #[test]
+    fn many_matches_saturate() {
+        let ones = |count| vec!["1"; count].join(" ");
+        let input = format!("Card 1: {} | {}", ones(9), ones(8));
+        let (cards, _) = crate::parse::parse_lenient(&input).unwrap();
+        assert_eq!(points(&cards[0], Duplicates::Collapse), 1);
+        assert_eq!(points(&cards[0], Duplicates::CountEach), 1 << 71);
+
+        let input = format!("Card 1: {} | {}", ones(12), ones(12));
+        let (cards, _) = crate::parse::parse_lenient(&input).unwrap();
+        assert_eq!(points(&cards[0], Duplicates::CountEach), u128::MAX);
+        assert_eq!(
+            calculate_points(&[cards[0].clone(), cards[0].clone()], Duplicates::CountEach),
+            u128::MAX
+        );
     }
 }
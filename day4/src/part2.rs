@@ -1,78 +1,34 @@
-// TODO: I know there's a dynamic programming solution that speeds up the runtime, just not sure
-// how to design and implement it
-use std::{
-    collections::{BTreeSet, VecDeque},
-    fs::read_to_string,
-};
+use crate::parse::{Card, Duplicates};
 
-type Cards = VecDeque<(Card, usize)>;
-
-#[derive(Clone, Debug)]
-struct Card {
-    winning: BTreeSet<usize>,
-    mine: BTreeSet<usize>,
-}
-
-pub fn solution() -> usize {
-    let input = read_to_string("day4/input.txt").unwrap();
-    calculate_scratch_cards(parse_input(&input))
+/// Counts the scratchcards held once every won copy has been scratched, saturating at
+/// `u128::MAX`.
+pub fn calculate_scratch_cards(cards: &[Card], duplicates: Duplicates) -> u128 {
+    copies(cards, duplicates)
+        .into_iter()
+        .fold(0, u128::saturating_add)
 }
 
-fn calculate_scratch_cards(mut cards: Cards) -> usize {
-    if let Some((top_card, count)) = cards.pop_front() {
-        let card_count = cards.len();
-        for _ in 0..count {
-            let score = score_card(&top_card);
-            if score == 0 {
-                break;
-            }
-
-            for num in 0..score {
-                cards[num % card_count].1 += 1;
-            };
+/// How many copies of each card end up held, original included.
+///
+/// Each card's matches are counted once; walking the deck in order, every copy of a card wins
+/// one more copy of each of the next `matches` cards, stopping at the end of the deck. Counts
+/// too large for a `u128`, which long decks of winning cards reach, saturate at `u128::MAX`.
+pub fn copies(cards: &[Card], duplicates: Duplicates) -> Vec<u128> {
+    let mut copies = vec![1u128; cards.len()];
+    for (i, card) in cards.iter().enumerate() {
+        let end = (i + 1 + card.matches(duplicates)).min(cards.len());
+        for j in i + 1..end {
+            copies[j] = copies[j].saturating_add(copies[i]);
         }
-        let recurrence = calculate_scratch_cards(cards);
-        count + recurrence
-    } else {
-        0
     }
-}
-
-fn score_card(card: &Card) -> usize {
-    card.winning.intersection(&card.mine).count()
-}
-
-fn parse_input(input: &str) -> Cards {
-    input
-        .lines()
-        .map(|line| {
-            let mut parts = line.split(':');
-            let numbers = parts.nth(1).expect("malformed card input");
-            let (winning, mine) = parse_numbers(numbers);
-            (Card { winning, mine }, 1)
-        })
-        .collect::<VecDeque<(Card, usize)>>()
-}
-
-fn parse_numbers(numbers: &str) -> (BTreeSet<usize>, BTreeSet<usize>) {
-    let mut parts = numbers.split('|');
-    let winning = parts.next().expect("malformed card input").trim();
-    let mine = parts.next().expect("malformed card input").trim();
 
-    (
-        winning
-            .split_whitespace()
-            .map(|n| n.parse().expect("not an ascii number"))
-            .collect::<BTreeSet<_>>(),
-        mine.split_whitespace()
-            .map(|n| n.parse().expect("not an ascii number"))
-            .collect::<BTreeSet<_>>(),
-    )
+    copies
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::parse_input;
 
     const INPUT: &str = r"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
@@ -83,20 +39,58 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
 
     #[test]
     fn example() {
-        assert_eq!(calculate_scratch_cards(parse_input(INPUT)), 30);
+        assert_eq!(
+            calculate_scratch_cards(&parse_input(INPUT).unwrap(), Duplicates::Collapse),
+            30
+        );
     }
 
     #[test]
     fn with_none_winning() {
-        let cards = parse_input("Card 1: 31 18 13 56 72 | 74 77 10 23 35 67 36 11");
-        assert_eq!(calculate_scratch_cards(cards), 1);
+        let cards = parse_input("Card 1: 31 18 13 56 72 | 74 77 10 23 35 67 36 11").unwrap();
+        assert_eq!(calculate_scratch_cards(&cards, Duplicates::Collapse), 1);
     }
 
     #[test]
     fn with_one_winning_card() {
         let input = r"Card 1: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
 Card 2: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        let cards = parse_input(input);
-        assert_eq!(calculate_scratch_cards(cards), 3);
+        let cards = parse_input(input).unwrap();
+        assert_eq!(calculate_scratch_cards(&cards, Duplicates::Collapse), 3);
+    }
+
+    #[test]
+    fn wins_stop_at_the_end_of_the_deck() {
+        let input = r"Card 1: 31 18 | 74 77
+Card 2: 41 92 | 41 92";
+        let cards = parse_input(input).unwrap();
+        assert_eq!(calculate_scratch_cards(&cards, Duplicates::Collapse), 2);
+    }
+
+    #[test]
+    fn large_decks() {
+        let input = (1..=100_000)
+            .map(|id| format!("Card {id}: 1 | 1"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let cards = parse_input(&input).unwrap();
+        assert_eq!(
+            calculate_scratch_cards(&cards, Duplicates::Collapse),
+            100_000 * 100_001 / 2
+        );
+    }
+
+    #[test]
+    fn huge_counts_saturate() {
+        let input = (1..=200)
+            .map(|id| format!("Card {id}: 1 2 3 4 5 6 7 8 9 10 | 1 2 3 4 5 6 7 8 9 10"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let cards = parse_input(&input).unwrap();
+        assert_eq!(copies(&cards, Duplicates::Collapse)[199], u128::MAX);
+        assert_eq!(
+            calculate_scratch_cards(&cards, Duplicates::Collapse),
+            u128::MAX
+        );
     }
 }
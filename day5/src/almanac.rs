@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+use crate::range_map::{compose, InverseMap, RangeMap};
+
+pub type Seeds = Vec<u64>;
+
+/// The maps from seeds to locations, each named after its header (like `seed-to-soil`) and
+/// applied in order.
+#[derive(Debug)]
+pub struct Almanac {
+    stages: Vec<(String, RangeMap)>,
+}
+
+impl Almanac {
+    pub fn new(stages: Vec<(String, RangeMap)>) -> Self {
+        Almanac { stages }
+    }
+
+    pub fn stages(&self) -> &[(String, RangeMap)] {
+        &self.stages
+    }
+
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps().fold(seed, |value, map| map.lookup(value))
+    }
+
+    /// The location ranges the seeds in `seeds` end up in, splitting ranges wherever they
+    /// straddle the edge of a rule. The result is neither sorted nor merged.
+    pub fn location_ranges(&self, seeds: Range<u64>) -> Vec<Range<u64>> {
+        self.maps().fold(vec![seeds], |ranges, map| {
+            ranges
+                .into_iter()
+                .flat_map(|range| map.map_range(range))
+                .collect()
+        })
+    }
+
+    /// A single map sending seeds straight to their locations.
+    pub fn flatten(&self) -> RangeMap {
+        self.maps()
+            .fold(RangeMap::default(), |flat, map| compose(&flat, map))
+    }
+
+    /// The flattened map read backwards, from locations to seeds.
+    pub fn invert(&self) -> InverseMap {
+        self.flatten().invert()
+    }
+
+    /// Every seed that ends up at `location`, found by walking the stages backwards.
+    pub fn seeds_at(&self, location: u64) -> Vec<u64> {
+        let mut values = vec![location];
+        for map in self.maps().rev() {
+            let inverse = map.invert();
+            values = values
+                .into_iter()
+                .flat_map(|value| inverse.preimages(value))
+                .collect();
+        }
+        values.sort_unstable();
+        values
+    }
+
+    /// The seed ranges that end up in `locations`, found by walking the stages backwards.
+    pub fn seed_ranges_in(&self, locations: Range<u64>) -> Vec<Range<u64>> {
+        self.maps().rev().fold(vec![locations], |ranges, map| {
+            let inverse = map.invert();
+            let mut preimages = ranges
+                .into_iter()
+                .flat_map(|range| inverse.preimage_ranges(range))
+                .collect::<Vec<_>>();
+            preimages.sort_by_key(|range| range.start);
+            preimages
+        })
+    }
+
+    fn maps(&self) -> impl DoubleEndedIterator<Item = &RangeMap> {
+        self.stages.iter().map(|(_, map)| map)
+    }
+}
@@ -0,0 +1,43 @@
+mod almanac;
+mod parse;
+mod part1;
+mod part2;
+mod range_map;
+mod trace;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use almanac::{Almanac, Seeds};
+pub use range_map::{compose, InverseMap, Overlap, RangeMap, Rule};
+pub use trace::{Step, Trace};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Parsed = (Seeds, Almanac);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::parse_input(input).map_err(|err| err.with_day(Self::DAY))
+    }
+
+    fn part1((seeds, almanac): &Self::Parsed) -> Answer {
+        part1::lowest_location(almanac, seeds).into()
+    }
+
+    fn part2((seeds, almanac): &Self::Parsed) -> Answer {
+        part2::lowest_location(almanac, seeds).into()
+    }
+
+    /// Every seed's value in each category on the way to its location.
+    fn trace((seeds, almanac): &Self::Parsed) -> Vec<String> {
+        seeds
+            .iter()
+            .map(|&seed| almanac.trace(seed).to_string())
+            .collect()
+    }
+}
+
+aoc_common::answer_tests!(Day5);
@@ -1,14 +0,0 @@
-mod part1;
-mod part2;
-
-#[cfg(feature = "dhat-heap")]
-#[global_allocator]
-static ALLOC: dhat::Alloc = dhat::Alloc;
-
-fn main() {
-    #[cfg(feature = "dhat-heap")]
-    let _profiler = dhat::Profiler::new_heap();
-
-    println!("part 1: {}", part1::solution());
-    println!("part 2: {}", part2::solution());
-}
@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse, ParseError};
+
+use crate::{
+    almanac::{Almanac, Seeds},
+    range_map::{RangeMap, Rule},
+};
+
+const SEEDS_HINT: &str = "the almanac starts with `seeds:` and `start length` pairs, \
+                          like `seeds: 79 14 55 13`";
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+/// A map section, with its categories still pointing into the input for error messages.
+#[derive(Debug)]
+struct Section<'a> {
+    from: &'a str,
+    to: &'a str,
+    map: RangeMap,
+}
+
+pub fn parse_input(input: &str) -> Result<(Seeds, Almanac), ParseError> {
+    let mut sections = parse::sections(input).into_iter();
+    let seeds = sections.next().ok_or_else(|| {
+        ParseError::at(input, input, "missing seeds section").with_hint(SEEDS_HINT)
+    })?;
+    let seeds = parse_seeds(input, seeds)?;
+
+    let mut maps = HashMap::new();
+    for section in sections {
+        let section = parse_map(input, section)?;
+        if maps.contains_key(section.from) {
+            return Err(ParseError::at(
+                input,
+                section.from,
+                format!("`{}` is mapped more than once", section.from),
+            )
+            .with_hint("each category can only be mapped to one other"));
+        }
+        maps.insert(section.from, section);
+    }
+
+    let mut stages = Vec::new();
+    let mut visited = Vec::new();
+    let mut category = SEED;
+    // Where the current category was named, to point errors at.
+    let mut named_at = &input[input.len()..];
+    while category != LOCATION {
+        let Some(section) = maps.remove(category) else {
+            let message = if visited.contains(&category) {
+                format!("maps loop back to `{category}`")
+            } else {
+                format!("no map from `{category}`")
+            };
+            return Err(ParseError::at(input, named_at, message)
+                .with_hint("maps must lead from `seed` to `location`"));
+        };
+        stages.push((format!("{}-to-{}", section.from, section.to), section.map));
+        visited.push(category);
+        category = section.to;
+        named_at = section.to;
+    }
+
+    Ok((seeds, Almanac::new(stages)))
+}
+
+/// Parses the `seeds:` line, which both parts read: part 1 as seeds, part 2 as `start length`
+/// pairs. There must be at least one pair, and no pair may be empty.
+fn parse_seeds(source: &str, input: &str) -> Result<Seeds, ParseError> {
+    let (label, seeds) = parse::labeled(source, input).map_err(|err| err.with_hint(SEEDS_HINT))?;
+    if label != "seeds" {
+        return Err(ParseError::at(source, label, "expected `seeds:`").with_hint(SEEDS_HINT));
+    }
+
+    let words = seeds.split_whitespace().collect::<Vec<_>>();
+    let numbers = parse::numbers::<u64>(source, seeds)?;
+    match words.as_slice() {
+        [] => Err(
+            ParseError::at(source, &seeds[seeds.len()..], "expected at least one seed")
+                .with_hint(SEEDS_HINT),
+        ),
+        [.., last] if words.len() % 2 == 1 => {
+            Err(
+                ParseError::at(source, last, format!("seed `{last}` has no range length"))
+                    .with_hint(SEEDS_HINT),
+            )
+        }
+        _ => match numbers.chunks(2).position(|pair| pair[1] == 0) {
+            Some(pair) => Err(
+                ParseError::at(source, words[pair * 2 + 1], "seed range is empty")
+                    .with_hint(SEEDS_HINT),
+            ),
+            None => Ok(numbers),
+        },
+    }
+}
+
+fn parse_map<'a>(source: &str, input: &'a str) -> Result<Section<'a>, ParseError> {
+    let (header, lines) = input.split_once('\n').unwrap_or((input, ""));
+    let (from, to) = header
+        .strip_suffix(" map:")
+        .and_then(|name| name.split_once("-to-"))
+        .filter(|(from, to)| !from.is_empty() && !to.is_empty())
+        .ok_or_else(|| {
+            ParseError::at(source, header, "expected an `X-to-Y map:` header")
+                .with_hint("map sections start with a header like `seed-to-soil map:`")
+        })?;
+
+    let lines = lines.lines().collect::<Vec<_>>();
+    let rules = lines
+        .iter()
+        .map(|line| parse_rule(source, line))
+        .collect::<Result<_, _>>()?;
+    let map = RangeMap::new(rules).map_err(|overlap| {
+        ParseError::at(
+            source,
+            lines[overlap.second],
+            format!(
+                "source range overlaps the one in `{}`",
+                lines[overlap.first]
+            ),
+        )
+        .with_hint("each number may be mapped by at most one line of a map")
+    })?;
+    Ok(Section { from, to, map })
+}
+
+fn parse_rule(source: &str, line: &str) -> Result<Rule, ParseError> {
+    let numbers = parse::numbers::<u64>(source, line)?;
+    let &[destination, source_start, range] = numbers.as_slice() else {
+        return Err(ParseError::at(
+            source,
+            line,
+            format!("expected 3 numbers, found {}", numbers.len()),
+        )
+        .with_hint(
+            "map section must have exactly 3 numbers: \
+             destination start, source start and range length",
+        ));
+    };
+
+    match (
+        source_start.checked_add(range),
+        destination.checked_add(range),
+    ) {
+        (Some(source_end), Some(_)) => Ok(Rule {
+            source: source_start..source_end,
+            destination,
+        }),
+        _ => Err(ParseError::at(
+            source,
+            line,
+            "range runs past the largest number",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("seeds: 79 14\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 9));
+        assert_eq!(err.message(), "no map from `soil`");
+
+        let err = parse_input("seeds: 79 14\n\nsoil map:\n50 98 2").unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 1));
+        assert_eq!(err.message(), "expected an `X-to-Y map:` header");
+
+        let source = "seeds: 79 1x";
+        let err = parse_seeds(source, source).unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 11));
+
+        for (source, column, message) in [
+            ("sedes: 79 14", 1, "expected `seeds:`"),
+            ("seeds:", 7, "expected at least one seed"),
+            ("seeds: 79 14 55", 14, "seed `55` has no range length"),
+            ("seeds: 79 14 55 0", 17, "seed range is empty"),
+        ] {
+            let err = parse_seeds(source, source).unwrap_err();
+            assert_eq!((err.column(), err.message()), (column, message), "{source}");
+        }
+
+        let source = "seed-to-soil map:\n50 98 2\n52 50";
+        let err = parse_map(source, source).unwrap_err();
+        assert_eq!((err.line(), err.column()), (3, 1));
+        assert_eq!(err.message(), "expected 3 numbers, found 2");
+        assert_eq!(
+            err.hint(),
+            Some(
+                "map section must have exactly 3 numbers: \
+                 destination start, source start and range length"
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let source = "seed-to-soil map:\n50 98 2\n52 50 48";
+        let section = parse_map(source, source).unwrap();
+        assert_eq!((section.from, section.to), ("seed", "soil"));
+        assert_eq!(section.map.lookup(98), 50);
+
+        let source = "seed-to-soil map:\n50 98 2\n52 50 48\n0 90 5";
+        let err = parse_map(source, source).unwrap_err();
+        assert_eq!((err.line(), err.column()), (4, 1));
+        assert_eq!(err.message(), "source range overlaps the one in `52 50 48`");
+    }
+
+    #[test]
+    fn test_categories() {
+        let source = "seeds: 79 1\n\nsoil-to-location map:\n0 80 5\n\n\
+                      water-to-soil map:\n\n\
+                      seed-to-soil map:\n81 79 1";
+        let (_, almanac) = parse_input(source).unwrap();
+        let names = almanac.stages().iter().map(|(name, _)| name.as_str());
+        assert_eq!(
+            names.collect::<Vec<_>>(),
+            vec!["seed-to-soil", "soil-to-location"]
+        );
+        assert_eq!(almanac.location(79), 1);
+
+        let source = "seeds: 79 1\n\nseed-to-soil map:\n\nseed-to-water map:";
+        let err = parse_input(source).unwrap_err();
+        assert_eq!((err.line(), err.column()), (5, 1));
+        assert_eq!(err.message(), "`seed` is mapped more than once");
+
+        let source = "seeds: 79 1\n\nseed-to-soil map:\n\nsoil-to-seed map:";
+        let err = parse_input(source).unwrap_err();
+        assert_eq!((err.line(), err.column()), (5, 9));
+        assert_eq!(err.message(), "maps loop back to `seed`");
+
+        let err = parse_input("seeds: 79 1").unwrap_err();
+        assert_eq!(err.message(), "no map from `seed`");
+    }
+}
@@ -1,220 +1,39 @@
-use std::{collections::HashMap, fs::read_to_string, ops::Range};
-use rayon::prelude::*;
-use indicatif::ParallelProgressIterator;
-
-type Seeds = Vec<usize>;
-type SeedSoilMap = HashMap<Range<usize>, Range<usize>>;
-type SoilFertilizerMap = HashMap<Range<usize>, Range<usize>>;
-type FertilizerWaterMap = HashMap<Range<usize>, Range<usize>>;
-type WaterLightMap = HashMap<Range<usize>, Range<usize>>;
-type LightTemperatureMap = HashMap<Range<usize>, Range<usize>>;
-type TemperatureHumidityMap = HashMap<Range<usize>, Range<usize>>;
-type HumidityLocationMap = HashMap<Range<usize>, Range<usize>>;
-
-pub fn solution() -> usize {
-    let input = read_to_string("day5/input.txt").expect("unable to read file");
-    let (seeds, almanac) = parse_input(&input);
-    almanac.lowest_location(&seeds)
-}
-
-#[derive(Debug)]
-struct Almanac {
-    seed_soil_map: SeedSoilMap,
-    soil_fertilizer_map: SoilFertilizerMap,
-    fertilizer_water_map: FertilizerWaterMap,
-    water_light_map: WaterLightMap,
-    light_temperature_map: LightTemperatureMap,
-    temperature_humidity_map: TemperatureHumidityMap,
-    humidity_location_map: HumidityLocationMap,
-}
-
-impl Almanac {
-    fn lowest_location(&self, seeds: &Seeds) -> usize {
-        seeds
-            .par_iter()
-            // .progress()
-            .map(|seed| {
-                let soil_loc = self
-                    .seed_soil_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(seed) {
-                            let offset = seed - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(*seed);
-                let fertilizer_loc = self
-                    .soil_fertilizer_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(&soil_loc) {
-                            let offset = soil_loc - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(soil_loc);
-                let water_loc = self
-                    .fertilizer_water_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(&fertilizer_loc) {
-                            let offset = fertilizer_loc - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(fertilizer_loc);
-                let light_loc = self
-                    .water_light_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(&water_loc) {
-                            let offset = water_loc - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(water_loc);
-                let temperature_loc = self
-                    .light_temperature_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(&light_loc) {
-                            let offset = light_loc - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(light_loc);
-                let humidity_loc = self
-                    .temperature_humidity_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(&temperature_loc) {
-                            let offset = temperature_loc - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(temperature_loc);
-                self.humidity_location_map
-                    .iter()
-                    .find_map(|(src, dest)| {
-                        if src.contains(&humidity_loc) {
-                            let offset = humidity_loc - src.start;
-                            return Some(dest.start + offset);
-                        }
-                        None
-                    })
-                    .unwrap_or(humidity_loc)
-            })
-            .min()
-            .unwrap_or(0)
+use std::ops::Range;
+
+use crate::almanac::{Almanac, Seeds};
+
+/// Searches backwards from the lowest locations, stopping at the first piece of the inverted
+/// almanac that starts past a location already reached from one of the seeds.
+pub fn lowest_location(almanac: &Almanac, seeds: &Seeds) -> u64 {
+    let seeds = seed_ranges(seeds).collect::<Vec<_>>();
+    let mut lowest = None;
+    for piece in almanac.invert().pieces() {
+        if lowest.is_some_and(|lowest| piece.source.start >= lowest) {
+            break;
+        }
+        let origins = piece.destination_range();
+        for seeds in &seeds {
+            let start = seeds.start.max(origins.start);
+            if start < seeds.end.min(origins.end) {
+                let location = piece.source.start + (start - origins.start);
+                lowest = Some(lowest.map_or(location, |lowest: u64| lowest.min(location)));
+            }
+        }
     }
+    lowest.expect("parsing rejects almanacs without seeds")
 }
 
-fn parse_input(input: &str) -> (Seeds, Almanac) {
-    let mut parts = input.split("\n\n");
-
-    let seeds = parts.next().expect("malformed input; no seeds");
-    let seed_soil = parts.next().expect("malformed input; no seed-to-soil maps");
-    let soil_fertilizer = parts
-        .next()
-        .expect("malformed input; no soil-to-fertilizer map");
-    let fertilizer_water = parts
-        .next()
-        .expect("malformed input; fertilizer-to-water map");
-    let water_light = parts
-        .next()
-        .expect("malformed input; no water-to-light map");
-    let light_temperature = parts
-        .next()
-        .expect("malformed input; no light-to-temperature map");
-    let temperature_humidity = parts
-        .next()
-        .expect("malformed input; no temperature-to-humidity map");
-    let humidity_location = parts
-        .next()
-        .expect("malformed input; no humidity-to-location map");
-
-    let seeds = parse_seeds(seeds);
-
-    (
-        seeds,
-        Almanac {
-            seed_soil_map: parse_seed_soil_map(seed_soil),
-            soil_fertilizer_map: parse_soil_fertilizer_map(soil_fertilizer),
-            fertilizer_water_map: parse_fertilizer_water_map(fertilizer_water),
-            water_light_map: parse_water_light_map(water_light),
-            light_temperature_map: parse_light_temperature_map(light_temperature),
-            temperature_humidity_map: parse_temperature_humidity_map(temperature_humidity),
-            humidity_location_map: parse_humidity_location_map(humidity_location),
-        },
-    )
+/// Reads the seeds as `start length` pairs, which parsing guarantees are complete and non-empty.
+fn seed_ranges(seeds: &Seeds) -> impl Iterator<Item = Range<u64>> + '_ {
+    seeds
+        .chunks_exact(2)
+        .map(|pair| pair[0]..pair[0].saturating_add(pair[1]))
 }
 
-fn parse_seeds(input: &str) -> Seeds {
-    input
-        .split(':')
-        .nth(1)
-        .expect("malformed seeds")
-        .split_whitespace()
-        .map(|s| s.parse::<usize>().expect("invalid number"))
-        .collect::<Vec<_>>()
-        .windows(2)
-        .step_by(2)
-        .flat_map(|pair| (pair[0]..pair[0] + pair[1]).collect::<Vec<_>>())
-        .collect()
-}
-
-macro_rules! parse_map {
-    ($name:ident, $type:ty) => {
-        fn $name(input: &str) -> $type {
-            let mut map = <$type>::new();
-            input
-                .split(":\n")
-                .nth(1)
-                .expect("malformed map")
-                .lines()
-                .for_each(|line| {
-                    let mut parts = line.split(" ");
-                    let destination = parts
-                        .next()
-                        .expect("malformed map")
-                        .parse::<usize>()
-                        .expect("invalid number");
-                    let source = parts
-                        .next()
-                        .expect("malformed map")
-                        .parse::<usize>()
-                        .expect("invalid number");
-                    let range = parts
-                        .next()
-                        .expect("malformed map")
-                        .parse::<usize>()
-                        .expect("invalid number");
-
-                    map.insert(source..source + range, destination..destination + range);
-                });
-            map
-        }
-    };
-}
-
-parse_map!(parse_seed_soil_map, SeedSoilMap);
-parse_map!(parse_soil_fertilizer_map, SoilFertilizerMap);
-parse_map!(parse_fertilizer_water_map, FertilizerWaterMap);
-parse_map!(parse_water_light_map, WaterLightMap);
-parse_map!(parse_light_temperature_map, LightTemperatureMap);
-parse_map!(parse_temperature_humidity_map, TemperatureHumidityMap);
-parse_map!(parse_humidity_location_map, HumidityLocationMap);
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::parse_input;
 
     const INPUT: &str = r"seeds: 79 14 55 13
 
@@ -252,8 +71,50 @@ humidity-to-location map:
 
     #[test]
     fn example() {
-        let (seeds, almanac) = parse_input(INPUT);
-        dbg!(&seeds);
-        assert_eq!(almanac.lowest_location(&seeds), 46);
+        let (seeds, almanac) = parse_input(INPUT).unwrap();
+        assert_eq!(lowest_location(&almanac, &seeds), 46);
+
+        let locations = almanac.flatten();
+        let forward = seed_ranges(&seeds)
+            .flat_map(|seeds| locations.map_range(seeds))
+            .map(|locations| locations.start)
+            .min();
+        assert_eq!(forward, Some(46));
+    }
+
+    #[test]
+    fn stage_by_stage() {
+        let (_, almanac) = parse_input(INPUT).unwrap();
+        let flat = almanac.flatten();
+        // The two split ranges differently, so compare the locations they cover.
+        let covered = |ranges: Vec<Range<u64>>| {
+            let mut locations = ranges.into_iter().flatten().collect::<Vec<_>>();
+            locations.sort_unstable();
+            locations
+        };
+        for seeds in [40..110, 79..93, 55..68, 0..1] {
+            assert_eq!(
+                covered(almanac.location_ranges(seeds.clone())),
+                covered(flat.map_range(seeds))
+            );
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        let (_, almanac) = parse_input(INPUT).unwrap();
+        assert_eq!(almanac.seeds_at(46), vec![82]);
+        let inverse = almanac.invert();
+        for location in 0..120 {
+            let seeds = almanac.seeds_at(location);
+            assert_eq!(inverse.preimages(location), seeds);
+            for seed in seeds {
+                assert_eq!(almanac.location(seed), location);
+            }
+        }
+
+        let seeds = almanac.seed_ranges_in(46..47);
+        assert_eq!(seeds, vec![82..83]);
+        assert_eq!(inverse.preimage_ranges(46..47), seeds);
     }
 }
@@ -0,0 +1,316 @@
+use std::{fmt, ops::Range};
+
+/// Maps the numbers in `source` to consecutive numbers starting at `destination`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub source: Range<u64>,
+    pub destination: u64,
+}
+
+impl Rule {
+    pub fn destination_range(&self) -> Range<u64> {
+        self.destination..self.destination + (self.source.end - self.source.start)
+    }
+
+    /// The same rule read backwards, from its destinations to its sources.
+    pub fn invert(&self) -> Rule {
+        Rule {
+            source: self.destination_range(),
+            destination: self.source.start,
+        }
+    }
+
+    /// Where `value` goes, assuming it is one of the rule's sources or the end of them.
+    fn apply(&self, value: u64) -> u64 {
+        self.destination + (value - self.source.start)
+    }
+}
+
+/// Written as in the almanac: destination start, source start and length.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.source.end - self.source.start;
+        write!(f, "{} {} {len}", self.destination, self.source.start)
+    }
+}
+
+/// Two rules, by their position in the list given to [`RangeMap::new`], whose sources overlap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Overlap {
+    pub first: usize,
+    pub second: usize,
+}
+
+/// A map from numbers to numbers made of rules with disjoint sources. Numbers no rule covers
+/// map to themselves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    /// Sorted by source, with empty rules dropped.
+    rules: Vec<Rule>,
+}
+
+impl RangeMap {
+    pub fn new(rules: Vec<Rule>) -> Result<Self, Overlap> {
+        let mut order = (0..rules.len())
+            .filter(|&i| !rules[i].source.is_empty())
+            .collect::<Vec<_>>();
+        order.sort_by_key(|&i| rules[i].source.start);
+        if let Some(pair) = order
+            .windows(2)
+            .find(|pair| rules[pair[0]].source.end > rules[pair[1]].source.start)
+        {
+            return Err(Overlap {
+                first: pair[0].min(pair[1]),
+                second: pair[0].max(pair[1]),
+            });
+        }
+
+        let mut rules = rules.into_iter().map(Some).collect::<Vec<_>>();
+        let rules = order.iter().filter_map(|&i| rules[i].take()).collect();
+        Ok(RangeMap { rules })
+    }
+
+    /// The rules, ordered by source.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// The rule covering `value`, if any.
+    pub fn rule(&self, value: u64) -> Option<&Rule> {
+        let after = self
+            .rules
+            .partition_point(|rule| rule.source.start <= value);
+        self.rules[..after]
+            .last()
+            .filter(|rule| rule.source.contains(&value))
+    }
+
+    pub fn lookup(&self, value: u64) -> u64 {
+        self.rule(value).map_or(value, |rule| rule.apply(value))
+    }
+
+    /// The numbers `range` maps to, as one range per rule it crosses and per gap between them,
+    /// in the order of the sources they come from.
+    pub fn map_range(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        self.segments(range)
+            .into_iter()
+            .map(|(source, rule)| match rule {
+                Some(rule) => rule.apply(source.start)..rule.apply(source.end),
+                None => source,
+            })
+            .collect()
+    }
+
+    /// Splits `range` where rules start and end, pairing each piece with the rule covering it.
+    fn segments(&self, range: Range<u64>) -> Vec<(Range<u64>, Option<&Rule>)> {
+        let mut segments = Vec::new();
+        let first = self
+            .rules
+            .partition_point(|rule| rule.source.end <= range.start);
+        let mut start = range.start;
+        for rule in &self.rules[first..] {
+            if start >= range.end || rule.source.start >= range.end {
+                break;
+            }
+            if start < rule.source.start {
+                segments.push((start..rule.source.start, None));
+                start = rule.source.start;
+            }
+            let end = range.end.min(rule.source.end);
+            segments.push((start..end, Some(rule)));
+            start = end;
+        }
+        if start < range.end {
+            segments.push((start..range.end, None));
+        }
+        segments
+    }
+}
+
+/// A [`RangeMap`] read backwards. Different numbers can map to the same one, so each number has
+/// any number of preimages rather than a single value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InverseMap {
+    /// Every rule of the map, and the identity on the gaps between them, inverted and sorted by
+    /// source. Sources may overlap.
+    pieces: Vec<Rule>,
+}
+
+impl RangeMap {
+    pub fn invert(&self) -> InverseMap {
+        let mut pieces = self
+            .segments(0..u64::MAX)
+            .into_iter()
+            .map(|(source, rule)| {
+                let destination = rule.map_or(source.start, |rule| rule.apply(source.start));
+                Rule {
+                    source,
+                    destination,
+                }
+                .invert()
+            })
+            .collect::<Vec<_>>();
+        pieces.sort_by_key(|piece| piece.source.start);
+        InverseMap { pieces }
+    }
+}
+
+impl InverseMap {
+    /// The inverted rules, ordered by the numbers they map back from.
+    pub fn pieces(&self) -> &[Rule] {
+        &self.pieces
+    }
+
+    /// Every number that maps to `value`, in ascending order.
+    pub fn preimages(&self, value: u64) -> Vec<u64> {
+        let mut preimages = self
+            .candidates(value)
+            .filter(|piece| piece.source.contains(&value))
+            .map(|piece| piece.apply(value))
+            .collect::<Vec<_>>();
+        preimages.sort_unstable();
+        preimages
+    }
+
+    /// Every number that maps into `range`, as sorted ranges with adjacent ones merged.
+    pub fn preimage_ranges(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut preimages = self
+            .candidates(range.end.saturating_sub(1))
+            .filter_map(|piece| {
+                let start = range.start.max(piece.source.start);
+                let end = range.end.min(piece.source.end);
+                (start < end).then(|| piece.apply(start)..piece.apply(end))
+            })
+            .collect::<Vec<_>>();
+        preimages.sort_by_key(|preimage| preimage.start);
+
+        let mut merged = Vec::<Range<u64>>::new();
+        for preimage in preimages {
+            match merged.last_mut() {
+                Some(last) if last.end >= preimage.start => last.end = last.end.max(preimage.end),
+                _ => merged.push(preimage),
+            }
+        }
+        merged
+    }
+
+    /// The pieces that could cover `value` or anything below it.
+    fn candidates(&self, value: u64) -> impl Iterator<Item = &Rule> {
+        let after = self
+            .pieces
+            .partition_point(|piece| piece.source.start <= value);
+        self.pieces[..after].iter()
+    }
+}
+
+/// The map sending each number through `first` and then `second`.
+pub fn compose(first: &RangeMap, second: &RangeMap) -> RangeMap {
+    let mut rules = Vec::<Rule>::new();
+    for (source, rule) in first.segments(0..u64::MAX) {
+        let destination = rule.map_or(source.start, |rule| rule.apply(source.start));
+        let len = source.end - source.start;
+        for (middle, second_rule) in second.segments(destination..destination + len) {
+            let start = source.start + (middle.start - destination);
+            let rule = Rule {
+                source: start..start + (middle.end - middle.start),
+                destination: second_rule.map_or(middle.start, |rule| rule.apply(middle.start)),
+            };
+            if rule.destination == rule.source.start {
+                continue;
+            }
+            match rules.last_mut() {
+                Some(last)
+                    if last.source.end == rule.source.start
+                        && last.destination_range().end == rule.destination =>
+                {
+                    last.source.end = rule.source.end;
+                }
+                _ => rules.push(rule),
+            }
+        }
+    }
+    RangeMap { rules }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rule(destination: u64, source: u64, len: u64) -> Rule {
+        Rule {
+            source: source..source + len,
+            destination,
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let map = RangeMap::new(vec![rule(50, 98, 2), rule(52, 50, 48)]).unwrap();
+        assert_eq!(map.rules()[0].source, 50..98);
+        assert_eq!(
+            [0, 49, 50, 79, 97, 98, 99, 100].map(|value| map.lookup(value)),
+            [0, 49, 52, 81, 99, 50, 51, 100]
+        );
+        assert_eq!(RangeMap::default().lookup(7), 7);
+    }
+
+    #[test]
+    fn test_map_range() {
+        let map = RangeMap::new(vec![rule(50, 98, 2), rule(52, 50, 48)]).unwrap();
+        assert_eq!(
+            map.map_range(40..110),
+            vec![40..50, 52..100, 50..52, 100..110]
+        );
+        assert_eq!(map.map_range(60..70), vec![62..72]);
+        assert!(map.map_range(5..5).is_empty());
+
+        let sparse = RangeMap::new(vec![rule(0, 10, 5), rule(100, 20, 5)]).unwrap();
+        assert_eq!(sparse.map_range(12..22), vec![2..5, 15..20, 100..102]);
+    }
+
+    #[test]
+    fn test_overlap() {
+        let overlap = RangeMap::new(vec![rule(0, 10, 5), rule(0, 30, 5), rule(0, 14, 2)]);
+        assert_eq!(
+            overlap,
+            Err(Overlap {
+                first: 0,
+                second: 2
+            })
+        );
+        assert!(RangeMap::new(vec![rule(0, 10, 5), rule(0, 15, 5), rule(0, 12, 0)]).is_ok());
+    }
+
+    #[test]
+    fn test_compose() {
+        let first = RangeMap::new(vec![rule(50, 98, 2), rule(52, 50, 48)]).unwrap();
+        let second =
+            RangeMap::new(vec![rule(0, 15, 37), rule(37, 52, 2), rule(39, 0, 15)]).unwrap();
+        let composed = compose(&first, &second);
+        for value in 0..120 {
+            assert_eq!(composed.lookup(value), second.lookup(first.lookup(value)));
+        }
+
+        let there = RangeMap::new(vec![rule(20, 10, 5)]).unwrap();
+        let back = RangeMap::new(vec![rule(10, 20, 5)]).unwrap();
+        // 10..15 goes there and back again, leaving only 20..25 moved by `back`.
+        assert_eq!(compose(&there, &back).rules(), [rule(10, 20, 5)]);
+        assert_eq!(compose(&there, &RangeMap::default()), there);
+    }
+
+    #[test]
+    fn test_invert() {
+        let map = RangeMap::new(vec![rule(50, 98, 2), rule(52, 50, 48)]).unwrap();
+        let inverse = map.invert();
+        assert_eq!(inverse.preimages(81), vec![79]);
+        assert_eq!(inverse.preimages(50), vec![98]);
+        assert_eq!(inverse.preimages(5), vec![5]);
+
+        // 98 and 99 map to 50 and 51, and so do 50 and 51 themselves.
+        let many = RangeMap::new(vec![rule(50, 98, 2)]).unwrap().invert();
+        assert_eq!(many.preimages(51), vec![51, 99]);
+        assert_eq!(many.preimages(98), Vec::<u64>::new());
+        assert_eq!(many.preimage_ranges(45..52), vec![45..52, 98..100]);
+        assert_eq!(many.preimage_ranges(97..101), vec![97..98, 100..101]);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt;
+
+use crate::{almanac::Almanac, range_map::Rule};
+
+/// The value reached in one category, and the rule that mapped it there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    pub category: String,
+    pub value: u64,
+    /// `None` when no rule covered the previous value, so it was kept as it was.
+    pub rule: Option<Rule>,
+}
+
+/// A seed's path through every category to its location.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    pub category: String,
+    pub seed: u64,
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn location(&self) -> u64 {
+        self.steps.last().map_or(self.seed, |step| step.value)
+    }
+}
+
+/// Formats the trace on one line, like `seed 79 -> soil 81 (52 50 48) -> ...`.
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.category, self.seed)?;
+        for step in &self.steps {
+            write!(f, " -> {} {}", step.category, step.value)?;
+            match &step.rule {
+                Some(rule) => write!(f, " ({rule})")?,
+                None => write!(f, " (identity)")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Almanac {
+    pub fn trace(&self, seed: u64) -> Trace {
+        let mut value = seed;
+        let steps = self
+            .stages()
+            .iter()
+            .map(|(name, map)| {
+                let rule = map.rule(value).cloned();
+                value = map.lookup(value);
+                Step {
+                    category: categories(name).1.to_string(),
+                    value,
+                    rule,
+                }
+            })
+            .collect();
+        let category = self
+            .stages()
+            .first()
+            .map_or("seed", |(name, _)| categories(name).0);
+        Trace {
+            category: category.to_string(),
+            seed,
+            steps,
+        }
+    }
+}
+
+/// Splits a stage name like `seed-to-soil` into the categories it maps between.
+fn categories(name: &str) -> (&str, &str) {
+    name.split_once("-to-").unwrap_or((name, name))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parse::parse_input;
+
+    const INPUT: &str = r"seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-location map:
+0 15 37
+37 52 2
+39 0 15";
+
+    #[test]
+    fn test_trace() {
+        let (_, almanac) = parse_input(INPUT).unwrap();
+        let trace = almanac.trace(79);
+        assert_eq!(trace.location(), 81);
+        assert_eq!(
+            trace.steps[0].rule.as_ref().unwrap().to_string(),
+            "52 50 48"
+        );
+        assert_eq!(
+            trace.to_string(),
+            "seed 79 -> soil 81 (52 50 48) -> location 81 (identity)"
+        );
+        assert_eq!(
+            almanac.trace(14).to_string(),
+            "seed 14 -> soil 14 (identity) -> location 53 (39 0 15)"
+        );
+    }
+}
@@ -0,0 +1,30 @@
+mod parse;
+mod part1;
+mod part2;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use parse::{Races, Sheet};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const MANIFEST_DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Parsed = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse::parse_input(input).map_err(|err| err.with_day(Self::DAY))
+    }
+
+    fn part1(sheet: &Self::Parsed) -> Answer {
+        part1::calculate_product_of_times(&sheet.races).into()
+    }
+
+    fn part2(sheet: &Self::Parsed) -> Answer {
+        part2::calculate_product_of_times(&sheet.race).into()
+    }
+}
+
+aoc_common::answer_tests!(Day6);
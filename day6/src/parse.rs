@@ -0,0 +1,103 @@
+use aoc_common::{parse, ParseError};
+
+pub type Races = Vec<(usize, usize)>;
+
+/// The sheet read both ways: as separate races, and as the one long race part 2 finds once the
+/// spaces between the numbers are taken for bad kerning.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sheet {
+    pub races: Races,
+    pub race: (usize, usize),
+}
+
+pub fn parse_input(input: &str) -> Result<Sheet, ParseError> {
+    let mut lines = input.lines();
+    let end = &input[input.len()..];
+    let times = lines
+        .next()
+        .ok_or_else(|| ParseError::at(input, end, "missing `Time:` line"))?;
+    let distances = lines
+        .next()
+        .ok_or_else(|| ParseError::at(input, end, "missing `Distance:` line"))?;
+
+    let (times, time) = parse_values(input, times)?;
+    let (distances, distance) = parse_values(input, distances)?;
+    if times.len() != distances.len() {
+        return Err(ParseError::at(
+            input,
+            end,
+            format!(
+                "found {} times but {} distances",
+                times.len(),
+                distances.len()
+            ),
+        )
+        .with_hint("every race needs both a time and a distance"));
+    }
+
+    Ok(Sheet {
+        races: times.into_iter().zip(distances).collect(),
+        race: (time, distance),
+    })
+}
+
+/// Parses a line's numbers, and the number they make with the spaces between them removed.
+fn parse_values(input: &str, line: &str) -> Result<(Vec<usize>, usize), ParseError> {
+    let (_, values) = parse::labeled(input, line).map_err(|err| {
+        err.with_hint("the input is a `Time:` line followed by a `Distance:` line")
+    })?;
+    let numbers = parse::numbers::<usize>(input, values)?;
+    if numbers.is_empty() {
+        return Err(
+            ParseError::at(input, &line[line.len()..], "expected at least one number")
+                .with_hint("each race needs a time and a distance, like `Time: 7 15 30`"),
+        );
+    }
+    let joined = values.split_whitespace().collect::<String>();
+    let joined = joined.parse().map_err(|_| {
+        ParseError::at(
+            input,
+            values.trim_start(),
+            format!("`{joined}` is too large once the spaces are removed"),
+        )
+    })?;
+    Ok((numbers, joined))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("Time:      7  15   30").unwrap_err();
+        assert_eq!(err.message(), "missing `Distance:` line");
+
+        let err = parse_input("Time:      7  15   30\nDistance  9  40  200").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 1));
+
+        let err = parse_input("Time:      7  1S   30\nDistance:  9  40  200").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 15));
+
+        let err = parse_input("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+        assert_eq!(err.message(), "found 3 times but 2 distances");
+
+        let err = parse_input("Time:\nDistance:").unwrap_err();
+        assert_eq!((err.line(), err.column()), (1, 6));
+        assert_eq!(err.message(), "expected at least one number");
+
+        let err = parse_input("Time: 7 15\nDistance: 9 9999999999999999999").unwrap_err();
+        assert_eq!((err.line(), err.column()), (2, 11));
+        assert_eq!(
+            err.message(),
+            "`99999999999999999999` is too large once the spaces are removed"
+        );
+    }
+
+    #[test]
+    fn test_kerning() {
+        let sheet = parse_input("Time: 7 05\nDistance: 9 0").unwrap();
+        assert_eq!(sheet.races, vec![(7, 9), (5, 0)]);
+        assert_eq!(sheet.race, (705, 90));
+    }
+}
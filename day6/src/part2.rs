@@ -1,8 +1,3 @@
-pub fn solution() -> usize {
-    let input = std::fs::read_to_string("day6/input.txt").expect("unable to read file");
-    calculate_product_of_times(&parse_input(&input))
-}
-
 pub fn calculate_product_of_times(&(time, distance): &(usize, usize)) -> usize {
     (0..=time).filter(|&step| {
         let speed = step;
@@ -13,46 +8,18 @@ pub fn calculate_product_of_times(&(time, distance): &(usize, usize)) -> usize {
     }).count()
 }
 
-fn parse_input(input: &str) -> (usize, usize) {
-    let mut lines = input.lines();
-    let times = lines.next().expect("malformed input");
-    let distances = lines.next().expect("malformed input");
-
-    let times = times
-        .split(':')
-        .skip(1)
-        .next()
-        .expect("malformed input")
-        .split_whitespace()
-        .map(|s| s.trim())
-        .collect::<String>()
-        .parse::<usize>()
-        .expect("not a number");
-    let distances = distances
-        .split(':')
-        .skip(1)
-        .next()
-        .expect("malformed input")
-        .split_whitespace()
-        .map(|s| s.trim())
-        .collect::<String>()
-        .parse::<usize>()
-        .expect("not a number");
-
-    (times, distances)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parse::parse_input;
 
     const INPUT: &str = r"Time:      7  15   30
 Distance:  9  40  200";
 
     #[test]
     fn test_example() {
-        let input = parse_input(INPUT);
-        dbg!(input);
-        assert_eq!(71503, calculate_product_of_times(&input));
+        let sheet = parse_input(INPUT).unwrap();
+        assert_eq!(sheet.race, (71530, 940200));
+        assert_eq!(71503, calculate_product_of_times(&sheet.race));
     }
 }